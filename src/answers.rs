@@ -0,0 +1,324 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client,
+    config::Config,
+    input,
+    runner::{self, run_all, Entry, RunError, Timed, SOLUTIONS},
+};
+
+/// `input_root/<year>/answers.txt`
+pub fn ledger_path(input_root: impl AsRef<Path>, year: u32) -> PathBuf {
+    input_root
+        .as_ref()
+        .join(year.to_string())
+        .join("answers.txt")
+}
+
+/// One line of the answer ledger: `day part [variant] answer`
+#[derive(Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<String>,
+    pub answer: String,
+}
+
+#[derive(Default)]
+pub struct Ledger {
+    entries: Vec<Expected>,
+}
+
+impl Ledger {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let (day, part, variant, answer) = match fields[..] {
+                [day, part, answer] => (day, part, None, answer),
+                [day, part, variant, answer] => (day, part, Some(variant.to_string()), answer),
+                _ => {
+                    return Err(format!(
+                        "line {}: expected `day part [variant] answer`",
+                        i + 1
+                    ))
+                }
+            };
+
+            entries.push(Expected {
+                day: day
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid day `{day}`", i + 1))?,
+                part: part
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid part `{part}`", i + 1))?,
+                variant,
+                answer: answer.to_string(),
+            });
+        }
+
+        Ok(Ledger { entries })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Appends a confirmed answer to the ledger file, unless the part already
+    /// has an entry. Returns whether it was added.
+    pub fn record(path: impl AsRef<Path>, day: u32, part: u32, answer: &str) -> io::Result<bool> {
+        let path = path.as_ref();
+        let ledger = match Self::load(path) {
+            Ok(ledger) => ledger,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e),
+        };
+        if ledger.get(day, part, None).is_some() {
+            return Ok(false);
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{day} {part} {answer}")?;
+        Ok(true)
+    }
+
+    /// The expected answer for a solution. Variants fall back to the answer of
+    /// their part, since every variant has to agree with the default solution.
+    pub fn get(&self, day: u32, part: u32, variant: Option<&str>) -> Option<&str> {
+        let find = |variant: Option<&str>| {
+            self.entries
+                .iter()
+                .find(|e| e.day == day && e.part == part && e.variant.as_deref() == variant)
+        };
+
+        find(variant)
+            .or_else(|| find(None))
+            .map(|e| e.answer.as_str())
+    }
+}
+
+pub enum Outcome {
+    /// No ledger entry for this day/part
+    Unknown,
+    Wrong {
+        expected: String,
+        actual: String,
+    },
+    Failed(String),
+    /// A differently laid out copy of the input gave another result than the
+    /// normalized one, see `check_layouts`
+    Layout {
+        layout: &'static str,
+        expected: String,
+        actual: String,
+    },
+}
+
+pub struct Mismatch {
+    pub solution: &'static Entry,
+    pub outcome: Outcome,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.outcome {
+            Outcome::Unknown => write!(f, "{}: no expected answer", self.solution),
+            Outcome::Wrong { expected, actual } => {
+                write!(f, "{}: expected {expected}, got {actual}", self.solution)
+            }
+            Outcome::Failed(e) => write!(f, "{}: failed: {e}", self.solution),
+            Outcome::Layout {
+                layout,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: {layout}: expected {expected}, got {actual}",
+                self.solution
+            ),
+        }
+    }
+}
+
+/// Runs every registered solution of `year` on its input under the configured
+/// input root and compares the answers with the ledger. Each input is parsed
+/// only once.
+pub fn check(ledger: &Ledger, year: u32, config: &Config) -> Vec<Mismatch> {
+    let mut mismatches = vec![];
+
+    for day in runner::days(year) {
+        let Some(input) = read_input(year, day, config.inputs(), &mut mismatches) else {
+            continue;
+        };
+
+        let options = config.options(year, day);
+        for (solution, result) in run_all(day_solutions(year, day), &input, &options) {
+            let outcome = match result {
+                Err(e) => Some(Outcome::Failed(e.to_string())),
+                Ok(timed) => match ledger.get(solution.day, solution.part, solution.variant) {
+                    None => Some(Outcome::Unknown),
+                    Some(expected) if expected != timed.answer.to_string() => {
+                        Some(Outcome::Wrong {
+                            expected: expected.to_string(),
+                            actual: timed.answer.to_string(),
+                        })
+                    }
+                    Some(_) => None,
+                },
+            };
+
+            if let Some(outcome) = outcome {
+                mismatches.push(Mismatch { solution, outcome });
+            }
+        }
+    }
+
+    mismatches
+}
+
+/// Runs every registered solution of `year` on differently laid out copies of
+/// its real input (CRLF line endings, no trailing newline, ...) and compares
+/// the results with those for the normalized input. No ledger is needed.
+pub fn check_layouts(year: u32, config: &Config) -> Vec<Mismatch> {
+    let mut mismatches = vec![];
+
+    for day in runner::days(year) {
+        let Some(input) = read_input(year, day, config.inputs(), &mut mismatches) else {
+            continue;
+        };
+        let options = config.options(year, day);
+
+        let result_text = |result: Result<Timed, RunError>| match result {
+            Ok(timed) => timed.answer.to_string(),
+            Err(e) => format!("failed: {e}"),
+        };
+        let mut layouts = input::layouts(&input).into_iter();
+        let (_, lf) = layouts.next().unwrap();
+        let expected = run_all(day_solutions(year, day), &lf, &options)
+            .into_iter()
+            .map(|(_, result)| result_text(result))
+            .collect::<Vec<_>>();
+
+        for (layout, input) in layouts {
+            for ((solution, result), expected) in
+                run_all(day_solutions(year, day), &input, &options)
+                    .into_iter()
+                    .zip(&expected)
+            {
+                let actual = result_text(result);
+                if actual != *expected {
+                    mismatches.push(Mismatch {
+                        solution,
+                        outcome: Outcome::Layout {
+                            layout,
+                            expected: expected.clone(),
+                            actual,
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    mismatches
+}
+
+fn day_solutions(year: u32, day: u32) -> impl Iterator<Item = &'static Entry> {
+    SOLUTIONS
+        .iter()
+        .filter(move |s| s.year == year && s.day == day)
+}
+
+/// Reads the day's input, recording a failure for each of the day's solutions
+/// if that isn't possible
+fn read_input(
+    year: u32,
+    day: u32,
+    input_root: &Path,
+    mismatches: &mut Vec<Mismatch>,
+) -> Option<String> {
+    fs::read_to_string(client::input_path(input_root, year, day))
+        .inspect_err(|e| {
+            mismatches.extend(day_solutions(year, day).map(|solution| Mismatch {
+                solution,
+                outcome: Outcome::Failed(format!("reading input: {e}")),
+            }))
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ledger() {
+        let ledger = Ledger::parse(
+            "# comment
+2 1 572
+2 1 naive 571
+
+17 1 6,5,7,4",
+        )
+        .unwrap();
+
+        assert_eq!(ledger.get(2, 1, None), Some("572"));
+        assert_eq!(ledger.get(2, 1, Some("naive")), Some("571"));
+        assert_eq!(ledger.get(2, 1, Some("other")), Some("572"));
+        assert_eq!(ledger.get(17, 1, None), Some("6,5,7,4"));
+        assert_eq!(ledger.get(2, 2, None), None);
+        assert!(Ledger::parse("2 1").is_err());
+        assert!(Ledger::parse("x 1 5").is_err());
+    }
+
+    #[test]
+    fn record_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert!(Ledger::record(&path, 18, 1, "22").unwrap());
+        assert!(!Ledger::record(&path, 18, 1, "23").unwrap());
+        assert!(Ledger::record(&path, 18, 2, "6,1").unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "18 1 22\n18 2 6,1\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    fn assert_none(mismatches: Vec<Mismatch>) {
+        assert!(
+            mismatches.is_empty(),
+            "\n{}",
+            mismatches
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+
+    // takes a while in debug builds, run with `cargo test --release -- --ignored`
+    #[test]
+    #[ignore]
+    fn real_inputs() {
+        let ledger = Ledger::load("input/2024/answers.txt").unwrap();
+        assert_none(check(&ledger, 2024, &Config::default()));
+    }
+
+    #[test]
+    #[ignore]
+    fn real_input_layouts() {
+        assert_none(check_layouts(2024, &Config::default()));
+    }
+}
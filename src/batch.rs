@@ -0,0 +1,181 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::Ledger,
+    report,
+    runner::{run_all, Entry, Options, RunError, Timed, SOLUTIONS},
+};
+
+/// Expected answers for `<name>.txt` go in `<name>.answers.txt`, in the format
+/// of the answer ledger
+const ANSWERS: &str = ".answers.txt";
+
+/// The input files in `dir` by name, each with its expected answers if there
+/// are any
+pub fn inputs(dir: impl AsRef<Path>) -> io::Result<Vec<(PathBuf, Option<Ledger>)>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.sort();
+
+    let mut inputs = vec![];
+    for path in paths {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if !path.is_file() || name.starts_with('.') || name.ends_with(ANSWERS) {
+            continue;
+        }
+
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let answers = path.with_file_name(format!("{stem}{ANSWERS}"));
+        let ledger = match Ledger::load(&answers) {
+            Ok(ledger) => Some(ledger),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("{}: {e}", answers.display()),
+                ))
+            }
+        };
+        inputs.push((path, ledger));
+    }
+
+    Ok(inputs)
+}
+
+/// One solution on one of the inputs
+pub struct BatchResult {
+    pub input: String,
+    pub solution: &'static Entry,
+    pub result: Result<Timed, RunError>,
+    pub expected: Option<String>,
+}
+
+impl BatchResult {
+    /// Failed, or gave another answer than expected
+    pub fn is_wrong(&self) -> bool {
+        match (&self.result, &self.expected) {
+            (Err(_), _) => true,
+            (Ok(timed), Some(expected)) => timed.answer.to_string() != *expected,
+            (Ok(_), None) => false,
+        }
+    }
+}
+
+/// Runs every solution and variant of `day` on each input in `dir`, see
+/// `inputs`
+pub fn run(
+    year: u32,
+    day: u32,
+    dir: impl AsRef<Path>,
+    options: &Options,
+) -> io::Result<Vec<BatchResult>> {
+    let mut results = vec![];
+    for (path, ledger) in inputs(dir)? {
+        let input = fs::read_to_string(&path)?;
+        let solutions = SOLUTIONS.iter().filter(|s| s.year == year && s.day == day);
+
+        for (solution, result) in run_all(solutions, &input, options) {
+            let expected = ledger
+                .as_ref()
+                .and_then(|ledger| ledger.get(day, solution.part, solution.variant));
+            results.push(BatchResult {
+                input: path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                solution,
+                result,
+                expected: expected.map(String::from),
+            });
+        }
+    }
+
+    Ok(results)
+}
+
+/// One row per input and solution, with the expected answer where it differs
+pub fn summary(results: &[BatchResult]) -> String {
+    let mut rows = vec![[
+        "input", "part", "variant", "answer", "parse", "solve", "check",
+    ]
+    .map(String::from)
+    .to_vec()];
+
+    for result in results {
+        let (answer, parse, solve) = match &result.result {
+            Ok(timed) => (
+                timed.answer.to_string(),
+                format!("{:.2?}", timed.parse),
+                format!("{:.2?}", timed.solve),
+            ),
+            Err(e) => (e.to_string(), "-".to_string(), "-".to_string()),
+        };
+        let check = match (&result.result, &result.expected) {
+            (Err(_), _) => "failed".to_string(),
+            (Ok(_), None) => "-".to_string(),
+            (Ok(_), Some(expected)) if result.is_wrong() => format!("expected {expected}"),
+            (Ok(_), Some(_)) => "ok".to_string(),
+        };
+
+        rows.push(vec![
+            result.input.clone(),
+            result.solution.part.to_string(),
+            result.solution.variant.unwrap_or("").to_string(),
+            answer,
+            parse,
+            solve,
+            check,
+        ]);
+    }
+
+    let mut table = report::table(&rows);
+    let wrong = results.iter().filter(|r| r.is_wrong()).count();
+    let checked = results.iter().filter(|r| r.expected.is_some()).count();
+    table += &format!(
+        "{} results, {checked} checked, {wrong} wrong or failed\n",
+        results.len()
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_batches() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("alice.txt"), "125 17\n").unwrap();
+        fs::write(dir.join("alice.answers.txt"), "11 1 55312\n11 2 1\n").unwrap();
+        fs::write(dir.join("bob.txt"), "0\n").unwrap();
+        fs::write(dir.join("carol.txt"), "x\n").unwrap();
+
+        let results = run(2024, 11, &dir, &Options::default()).unwrap();
+        let summary = summary(&results);
+        let summary = summary
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(summary.len(), 8);
+        assert_eq!(summary[1][..3], ["alice.txt", "1", "55312"]);
+        assert_eq!(summary[1].last(), Some(&"ok"));
+        assert_eq!(summary[2][..3], ["alice.txt", "2", "65601038650482"]);
+        assert_eq!(summary[2][5..], ["expected", "1"]);
+        assert_eq!(summary[3].last(), Some(&"-"));
+        assert_eq!(summary[5].last(), Some(&"failed"));
+        assert_eq!(
+            summary[7].join(" "),
+            "6 results, 2 checked, 3 wrong or failed"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    memory::format_bytes,
+    runner::{run_all, Entry, Options, RunError},
+};
+
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    /// Size of the input, used for the throughput
+    pub bytes: usize,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>, bytes: usize) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+
+        Stats {
+            iterations: n,
+            min: samples[0],
+            median: samples[n / 2],
+            p95: samples[((n * 95).div_ceil(100)).clamp(1, n) - 1],
+            bytes,
+        }
+    }
+
+    /// Input bytes per second, based on the median
+    pub fn throughput(&self) -> f64 {
+        self.bytes as f64 / self.median.as_secs_f64()
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, p95 {:?}, {}/s ({} runs)",
+            self.min,
+            self.median,
+            self.p95,
+            format_bytes(self.throughput() as u64),
+            self.iterations
+        )
+    }
+}
+
+/// Runs `solution` up to `iterations` times, or until `budget` is spent.
+/// The solution always runs at least once. Each run is like `run_all`, with
+/// its timeout and without letting a panic through, and the first that fails
+/// ends the benchmark.
+pub fn bench(
+    solution: &Entry,
+    input: &str,
+    options: &Options,
+    iterations: usize,
+    budget: Duration,
+) -> Result<Stats, RunError> {
+    let mut samples = vec![];
+    let start = Instant::now();
+
+    while samples.is_empty() || (samples.len() < iterations && start.elapsed() < budget) {
+        for (_, result) in run_all([solution], input, options) {
+            let timed = result?;
+            samples.push(timed.parse + timed.solve);
+        }
+    }
+
+    Ok(Stats::from_samples(samples, input.len()))
+}
+
+fn key(solution: &Entry) -> String {
+    format!(
+        "{}\t{}\t{}\t{}",
+        solution.year,
+        solution.day,
+        solution.part,
+        solution.variant.unwrap_or("-")
+    )
+}
+
+/// Previous benchmark results, one line per run:
+/// `timestamp year day part variant min_ns median_ns p95_ns`
+#[derive(Default)]
+pub struct History {
+    latest: HashMap<String, Duration>,
+}
+
+impl History {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut latest = HashMap::new();
+        for line in content.lines() {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [_, year, day, part, variant, _, median, _] = fields[..] else {
+                continue;
+            };
+            if let Ok(median) = median.parse() {
+                latest.insert(
+                    format!("{year}\t{day}\t{part}\t{variant}"),
+                    Duration::from_nanos(median),
+                );
+            }
+        }
+
+        Ok(History { latest })
+    }
+
+    /// Median of the last recorded run of `solution`
+    pub fn previous(&self, solution: &Entry) -> Option<Duration> {
+        self.latest.get(&key(solution)).copied()
+    }
+
+    pub fn append(path: impl AsRef<Path>, solution: &Entry, stats: &Stats) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{timestamp}\t{}\t{}\t{}\t{}",
+            key(solution),
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.p95.as_nanos()
+        )
+    }
+}
+
+/// Relative change of the median compared to a previous run, e.g. `-12.5%`,
+/// or `n/a` if the previous run was too fast to measure
+pub fn change(previous: Duration, current: Duration) -> String {
+    if previous.is_zero() {
+        return "n/a".to_string();
+    }
+
+    let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.) * 100.;
+    format!("{change:+.1}%")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples, 1000);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(51));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(stats.throughput(), 1000. / 0.051);
+
+        let stats = Stats::from_samples(vec![Duration::from_millis(3)], 0);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn history() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.tsv", std::process::id()));
+        fs::write(&path, "1\t2024\t1\t1\t-\t10\t20\t30\n").unwrap();
+
+        let day1 = crate::runner::find(2024, 1, Some(1), None).next().unwrap();
+        let day2 = crate::runner::find(2024, 2, Some(1), Some("naive"))
+            .next()
+            .unwrap();
+        let stats = Stats::from_samples(vec![Duration::from_nanos(40)], 0);
+        History::append(&path, day2, &stats).unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.previous(day1), Some(Duration::from_nanos(20)));
+        assert_eq!(history.previous(day2), Some(Duration::from_nanos(40)));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reports_failures() {
+        let day11 = crate::runner::find(2024, 11, Some(1), None).next().unwrap();
+        let stats = bench(day11, "125 17", &Options::default(), 3, Duration::MAX).unwrap();
+        assert_eq!(stats.iterations, 3);
+
+        let result = bench(day11, "125 x", &Options::default(), 3, Duration::MAX);
+        assert!(matches!(result, Err(RunError::Parse(_))));
+    }
+
+    #[test]
+    fn relative_change() {
+        let ms = Duration::from_millis;
+        assert_eq!(change(ms(100), ms(50)), "-50.0%");
+        assert_eq!(change(ms(100), ms(125)), "+25.0%");
+        assert_eq!(change(ms(0), ms(125)), "n/a");
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{
+    input,
+    runner::{Answer, Day, Entry, RunError, Timed},
+    solution::Params,
+};
+
+/// Kept in the working directory
+pub const FILE: &str = "answer_cache.tsv";
+
+/// What an answer depends on: the solution, its input and parameters, and the
+/// build that computed it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: String,
+    input: u64,
+    build: u64,
+}
+
+impl Key {
+    fn new(solution: &Entry, input: u64, build: u64) -> Self {
+        Key {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            variant: solution.variant.unwrap_or("-").to_string(),
+            input,
+            build,
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Option<(Key, Answer)> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let [year, day, part, variant, input, build, kind, answer] = fields[..] else {
+        return None;
+    };
+
+    let key = Key {
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        variant: variant.to_string(),
+        input: u64::from_str_radix(input, 16).ok()?,
+        build: u64::from_str_radix(build, 16).ok()?,
+    };
+    let answer = match kind {
+        "int" => Answer::Int(answer.parse().ok()?),
+        "string" => Answer::Text(answer.to_string()),
+        _ => return None,
+    };
+
+    Some((key, answer))
+}
+
+/// Identifies the input and parameters a day was run with
+pub fn input_hash(input: &str, params: &Params) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    input::normalize(input).hash(&mut hasher);
+    params.to_string().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Identifies the running executable, so that answers are computed again
+/// after any change to the code
+pub fn build_hash() -> io::Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    fs::read(env::current_exe()?)?.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+/// Answers of earlier runs, one line per answer:
+/// `year day part variant input_hash build_hash answer_type answer`.
+/// Entries of older builds are never matched again, delete the file to get
+/// rid of them.
+pub struct AnswerCache {
+    path: PathBuf,
+    build: u64,
+    answers: HashMap<Key, Answer>,
+}
+
+impl AnswerCache {
+    /// Malformed lines are skipped, since the cache can always be rebuilt
+    pub fn load(path: impl Into<PathBuf>, build: u64) -> io::Result<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(AnswerCache {
+            path,
+            build,
+            answers: content
+                .lines()
+                .filter_map(parse_line)
+                .filter(|(key, _)| key.build == build)
+                .collect(),
+        })
+    }
+
+    pub fn get(&self, solution: &Entry, input: u64) -> Option<&Answer> {
+        self.answers.get(&Key::new(solution, input, self.build))
+    }
+
+    pub fn insert(&mut self, solution: &Entry, input: u64, answer: &Answer) -> io::Result<()> {
+        let key = Key::new(solution, input, self.build);
+        if self.answers.get(&key) == Some(answer) {
+            return Ok(());
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{:016x}\t{:016x}\t{}\t{answer}",
+            key.year,
+            key.day,
+            key.part,
+            key.variant,
+            key.input,
+            key.build,
+            answer.kind()
+        )?;
+
+        self.answers.insert(key, answer.clone());
+        Ok(())
+    }
+
+    /// Runs the solutions of `days` that have no answer in the cache with
+    /// `run`, which gets the days with only those solutions left, and takes
+    /// the others from the cache. With `force` every solution runs. New
+    /// answers are added to the cache. Results keep the order of `days`.
+    pub fn run<'a>(
+        &mut self,
+        days: Vec<Day<'a>>,
+        force: bool,
+        run: impl FnOnce(&[Day<'a>]) -> Vec<(&'a Entry, Result<Timed, RunError>)>,
+    ) -> io::Result<Vec<(&'a Entry, Result<Timed, RunError>)>> {
+        let mut slots = vec![];
+        let mut remaining = vec![];
+        for day in days {
+            let hash = input_hash(&day.input, &day.options.params);
+            let (mut cached, mut missing) = (vec![], vec![]);
+            for entry in day.entries {
+                match self.get(entry, hash).filter(|_| !force) {
+                    Some(answer) => cached.push((entry, Some(cached_answer(answer)))),
+                    None => {
+                        cached.push((entry, None));
+                        missing.push(entry);
+                    }
+                }
+            }
+
+            slots.push((hash, cached));
+            remaining.push(Day {
+                entries: missing,
+                ..day
+            });
+        }
+
+        let mut computed = run(&remaining).into_iter();
+        let mut results = vec![];
+        for (hash, cached) in slots {
+            for (entry, answer) in cached {
+                let result = match answer {
+                    Some(timed) => Ok(timed),
+                    None => computed.next().expect("a result for every solution").1,
+                };
+                if let Ok(timed) = &result {
+                    self.insert(entry, hash, &timed.answer)?;
+                }
+                results.push((entry, result));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+fn cached_answer(answer: &Answer) -> Timed {
+    Timed {
+        answer: answer.clone(),
+        parse: Duration::ZERO,
+        solve: Duration::ZERO,
+        allocs: None,
+        cached: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{self, Options};
+
+    #[test]
+    fn caches_answers() {
+        let path = env::temp_dir().join(format!("aoc-cache-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let entries = runner::find(2024, 11, None, None).collect::<Vec<_>>();
+        let day = |input: &str| Day {
+            entries: entries.clone(),
+            input: input.to_string(),
+            options: Options::default(),
+        };
+        let run = |days: &[Day<'static>]| {
+            days.iter()
+                .flat_map(|day| runner::run_all(day.entries.clone(), &day.input, &day.options))
+                .collect()
+        };
+        let answers = |results: Vec<(&Entry, Result<Timed, RunError>)>| {
+            results
+                .into_iter()
+                .map(|(_, result)| {
+                    let timed = result.unwrap();
+                    (timed.answer.to_string(), timed.cached)
+                })
+                .collect::<Vec<_>>()
+        };
+        let (part1, part2) = ("55312".to_string(), "65601038650482".to_string());
+
+        let mut cache = AnswerCache::load(&path, 1).unwrap();
+        let first = cache.run(vec![day("125 17")], false, run).unwrap();
+        assert_eq!(
+            answers(first),
+            [(part1.clone(), false), (part2.clone(), false)]
+        );
+
+        // from the file, and only for the same input and build
+        let mut cache = AnswerCache::load(&path, 1).unwrap();
+        let cached = cache.run(vec![day("125 17\n")], false, run).unwrap();
+        assert_eq!(
+            answers(cached),
+            [(part1.clone(), true), (part2.clone(), true)]
+        );
+        let forced = cache.run(vec![day("125 17")], true, run).unwrap();
+        assert_eq!(answers(forced)[0], (part1.clone(), false));
+        let changed = cache.run(vec![day("125 18")], false, run).unwrap();
+        assert!(!answers(changed)[0].1);
+
+        let mut cache = AnswerCache::load(&path, 2).unwrap();
+        let rebuilt = cache.run(vec![day("125 17")], false, run).unwrap();
+        assert_eq!(answers(rebuilt)[1], (part2, false));
+
+        fs::remove_file(path).unwrap();
+    }
+}
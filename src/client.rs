@@ -0,0 +1,411 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Read when `AOC_SESSION` is not set
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = "github.com/Dewaeq/advent-of-code-2024";
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    Http(ureq::Error),
+    Io(io::Error),
+    /// A submission response we don't know how to read
+    UnexpectedResponse(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} or write it to {SESSION_FILE}"
+            ),
+            ClientError::Http(e) => write!(f, "request failed: {e}"),
+            ClientError::Io(e) => write!(f, "{e}"),
+            ClientError::UnexpectedResponse(text) => {
+                write!(f, "unexpected response to submission: {text}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// The session token from `AOC_SESSION`, or else from `.aoc-session`
+pub fn session_from_env() -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session.trim().to_string());
+    }
+
+    match fs::read_to_string(SESSION_FILE) {
+        Ok(session) => Ok(session.trim().to_string()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ClientError::NoSession),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// `AOC_BASE_URL`, or the real site
+pub fn base_url_from_env() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// The site's response to a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction
+    Wrong,
+    /// Submitted too soon after the previous attempt
+    Wait(Duration),
+    /// The part was solved before, so the answer wasn't checked
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict from the html the site responds with
+    pub fn parse(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if html.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if html.contains("You gave an answer too recently") {
+            // "You have 1m 5s left to wait."
+            let start = html.find("You have ")? + "You have ".len();
+            let end = start + html[start..].find(" left to wait")?;
+
+            let mut seconds = 0;
+            for amount in html[start..end].split_whitespace() {
+                seconds += match amount.split_at(amount.len() - 1) {
+                    (m, "m") => m.parse::<u64>().ok()? * 60,
+                    (s, "s") => s.parse::<u64>().ok()?,
+                    _ => return None,
+                };
+            }
+
+            Some(Verdict::Wait(Duration::from_secs(seconds)))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the site actually judged the answer
+    pub fn is_attempt(self) -> bool {
+        !matches!(self, Verdict::Wait(_) | Verdict::AlreadySolved)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(d) => write!(f, "wait-{}s", d.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "already-solved" => Verdict::AlreadySolved,
+            _ => {
+                let seconds = s
+                    .strip_prefix("wait-")
+                    .and_then(|s| s.strip_suffix('s'))
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| format!("unknown verdict `{s}`"))?;
+                Verdict::Wait(Duration::from_secs(seconds))
+            }
+        })
+    }
+}
+
+/// Talks to the puzzle site, waiting at least `min_interval` between requests
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            min_interval: Duration::from_secs(5),
+            last_request: None,
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        self.last_request = Some(Instant::now());
+    }
+
+    pub fn fetch_input(&mut self, year: u32, day: u32) -> Result<String, ClientError> {
+        self.throttle();
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()?
+            .body_mut()
+            .read_to_string()?;
+
+        Ok(input)
+    }
+
+    pub fn submit_answer(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        self.throttle();
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let html = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?
+            .body_mut()
+            .read_to_string()?;
+
+        Verdict::parse(&html).ok_or_else(|| {
+            let text = html.chars().take(200).collect();
+            ClientError::UnexpectedResponse(text)
+        })
+    }
+}
+
+/// `input_root/<year>/dayN.txt`
+pub fn input_path(input_root: impl AsRef<Path>, year: u32, day: u32) -> PathBuf {
+    input_root
+        .as_ref()
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Returns the path of the day's input, downloading it first unless it is
+/// already cached. Cached inputs are never downloaded again.
+pub fn download(
+    client: &mut Client,
+    input_root: impl AsRef<Path>,
+    year: u32,
+    day: u32,
+) -> Result<(PathBuf, bool), ClientError> {
+    let path = input_path(input_root, year, day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+
+    let input = client.fetch_input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+
+    Ok((path, true))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+    };
+
+    use super::*;
+
+    /// Answers every request with `body` and reports the request line,
+    /// headers and body of each request
+    pub(crate) fn stub_server(body: &'static str) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length: ") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+                tx.send(request).unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn download_caches_input() {
+        let (url, requests) = stub_server("1 2\n3 4\n");
+        let root = env::temp_dir().join(format!("aoc-download-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let mut client = Client::new(url, "abc").with_min_interval(Duration::ZERO);
+
+        let (path, downloaded) = download(&mut client, &root, 2024, 3).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, root.join("2024").join("day3.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/3/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+
+        let (_, downloaded) = download(&mut client, &root, 2024, 3).unwrap();
+        assert!(!downloaded);
+        assert!(requests.try_recv().is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn submit() {
+        let (url, requests) = stub_server(
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let mut client = Client::new(url, "abc").with_min_interval(Duration::ZERO);
+
+        let verdict = client.submit_answer(2024, 5, 2, "4944").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/5/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=4944"));
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        let wait = "You gave an answer too recently; you have to wait after submitting an \
+            answer before trying again.  You have 1m 5s left to wait.";
+
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::parse(wait),
+            Some(Verdict::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(Verdict::parse("<html>maintenance</html>"), None);
+
+        for verdict in [Verdict::TooHigh, Verdict::Wait(Duration::from_secs(30))] {
+            assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        }
+    }
+
+    #[test]
+    fn throttle() {
+        let mut client =
+            Client::new("http://localhost", "").with_min_interval(Duration::from_millis(50));
+        let start = Instant::now();
+        client.throttle();
+        client.throttle();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::{
+    client::{self, ClientError},
+    runner::Options,
+    solution::Params,
+};
+
+/// Read from the working directory, if it exists
+pub const FILE: &str = ".aoc.toml";
+
+/// Project settings, everything is optional:
+///
+/// ```toml
+/// inputs = "input"
+/// year = 2024
+/// # seconds, for parsing and for each solution
+/// timeout = 60
+/// # or { file = ".aoc-session" }, defaults to $AOC_SESSION and then .aoc-session
+/// session = { env = "AOC_SESSION" }
+///
+/// [day.2024.2]
+/// # run this instead of the default solution of each part that has it
+/// variant = "naive"
+///
+/// [day.2024.14]
+/// timeout = 120
+/// params = { rows = 103, cols = 101, max_seconds = 100_000 }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Directory with an `<year>/day<day>.txt` layout
+    pub inputs: Option<PathBuf>,
+    pub year: Option<u32>,
+    #[serde(deserialize_with = "seconds")]
+    pub timeout: Option<f64>,
+    pub session: Option<Session>,
+    /// Keyed by year and then by day number
+    pub day: BTreeMap<String, BTreeMap<String, DayConfig>>,
+}
+
+/// Where to read the session token from
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Session {
+    /// An environment variable
+    Env(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayConfig {
+    pub variant: Option<String>,
+    #[serde(deserialize_with = "seconds")]
+    pub timeout: Option<f64>,
+    /// Passed to the day's `WithParams::parse_with`
+    pub params: Params,
+}
+
+/// A number of seconds that fits a `Duration`, e.g. for `--timeout`
+pub fn parse_seconds(s: &str) -> Result<f64, String> {
+    let seconds = s.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds)
+        .map(|_| seconds)
+        .map_err(|_| format!("`{s}` is not a number of seconds"))
+}
+
+fn seconds<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    let seconds = Option::<f64>::deserialize(deserializer)?;
+    match seconds {
+        Some(s) if Duration::try_from_secs_f64(s).is_err() => Err(D::Error::custom(format!(
+            "`{s}` is not a number of seconds"
+        ))),
+        _ => Ok(seconds),
+    }
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// The defaults if there is no file at `path`
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn inputs(&self) -> &Path {
+        self.inputs.as_deref().unwrap_or(Path::new("input"))
+    }
+
+    pub fn day(&self, year: u32, day: u32) -> Option<&DayConfig> {
+        self.day.get(&year.to_string())?.get(&day.to_string())
+    }
+
+    /// The variant to run for `day` when none is given, see
+    /// `runner::find_preferring`
+    pub fn variant(&self, year: u32, day: u32) -> Option<&str> {
+        self.day(year, day)?.variant.as_deref()
+    }
+
+    /// Replaces every timeout in the file, e.g. with one from the command line
+    pub fn set_timeout(&mut self, seconds: f64) {
+        self.timeout = Some(seconds);
+        for day in self.day.values_mut().flat_map(|days| days.values_mut()) {
+            day.timeout = None;
+        }
+    }
+
+    /// How to run the solutions of `day`
+    pub fn options(&self, year: u32, day: u32) -> Options {
+        let day = self.day(year, day);
+        Options {
+            timeout: day
+                .and_then(|d| d.timeout)
+                .or(self.timeout)
+                .map(Duration::from_secs_f64),
+            params: day.map(|d| d.params.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// The session token from the configured source, or else the defaults of
+    /// `client::session_from_env`
+    pub fn session(&self) -> Result<String, ClientError> {
+        let session = match &self.session {
+            None => return client::session_from_env(),
+            Some(Session::Env(var)) => env::var(var).map_err(|_| ClientError::NoSession)?,
+            Some(Session::File(path)) => fs::read_to_string(path)?,
+        };
+
+        Ok(session.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let mut config = Config::parse(
+            r#"
+year = 2024
+timeout = 60
+session = { file = "token.txt" }
+
+[day.2024.2]
+variant = "naive"
+
+[day.2024.14]
+timeout = 0.5
+params = { rows = 7, cols = 11 }
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2024));
+        assert_eq!(config.inputs(), Path::new("input"));
+        assert!(
+            matches!(&config.session, Some(Session::File(path)) if path == Path::new("token.txt"))
+        );
+        assert_eq!(config.variant(2024, 2), Some("naive"));
+        assert_eq!(config.variant(2024, 14), None);
+        assert_eq!(config.variant(2023, 2), None);
+
+        let options = config.options(2024, 14);
+        assert_eq!(options.timeout, Some(Duration::from_millis(500)));
+        assert_eq!(options.params["rows"].as_integer(), Some(7));
+        assert!(config.options(2023, 14).params.is_empty());
+        assert_eq!(
+            config.options(2024, 1).timeout,
+            Some(Duration::from_secs(60))
+        );
+
+        config.set_timeout(2.);
+        assert_eq!(
+            config.options(2024, 14).timeout,
+            Some(Duration::from_secs(2))
+        );
+
+        assert!(Config::parse("yaer = 2024").is_err());
+        assert!(Config::parse("timeout = -1").is_err());
+        assert!(Config::parse("[day.2024.1]\ntimeout = nan").is_err());
+        assert_eq!(parse_seconds("0.5"), Ok(0.5));
+        assert!(parse_seconds("-3").is_err());
+        assert!(parse_seconds("inf").is_err());
+        assert!(Config::parse("[day.2024.1]\nparams = 3").is_err());
+        assert!(Config::parse("[day.1]\nvariant = \"naive\"").is_err());
+    }
+}
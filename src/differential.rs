@@ -0,0 +1,228 @@
+use std::fmt::Display;
+
+use fastrand::Rng;
+
+use crate::runner::{run_all, Entry, Options, SOLUTIONS};
+
+/// Solutions of the same day and part that gave different results on an input
+pub struct Disagreement {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// Where the input came from, e.g. a file name or a generator seed
+    pub source: String,
+    /// The smallest part of the input on which the solutions still disagree
+    pub input: String,
+    /// The answer, or the error, of each solution on the minimized input
+    pub results: Vec<(&'static Entry, String)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} Day {} - Part {}: solutions disagree on {}",
+            self.year, self.day, self.part, self.source
+        )?;
+        for (solution, result) in &self.results {
+            writeln!(f, "  {solution}: {result}")?;
+        }
+        write!(f, "minimized input:\n{}", self.input)
+    }
+}
+
+fn results(solutions: &[&'static Entry], input: &str, options: &Options) -> Vec<String> {
+    run_all(solutions.iter().copied(), input, options)
+        .into_iter()
+        .map(|(_, result)| match result {
+            Ok(timed) => timed.answer.to_string(),
+            Err(e) => format!("failed: {e}"),
+        })
+        .collect()
+}
+
+fn disagree(solutions: &[&'static Entry], input: &str, options: &Options) -> bool {
+    let results = results(solutions, input, options);
+    results.iter().any(|r| *r != results[0])
+}
+
+/// Removes as many lines from `input` as possible while `fails` still holds,
+/// first in large chunks and then in smaller ones (delta debugging)
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join =
+        |lines: &[&str]| -> String { lines.iter().map(|line| format!("{line}\n")).collect() };
+
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let mut chunks = 2;
+    while lines.len() >= 2 {
+        let size = lines.len().div_ceil(chunks);
+        let reduced = (0..lines.len()).step_by(size).find_map(|start| {
+            let mut rest = lines[..start].to_vec();
+            rest.extend(&lines[(start + size).min(lines.len())..]);
+            fails(&join(&rest)).then_some(rest)
+        });
+
+        if let Some(rest) = reduced {
+            lines = rest;
+            chunks = (chunks - 1).max(2);
+        } else if size == 1 {
+            break;
+        } else {
+            chunks = (chunks * 2).min(lines.len());
+        }
+    }
+
+    join(&lines)
+}
+
+/// Runs all solutions for the same day and part on `input`, and returns the
+/// minimized input if they don't all agree
+pub fn compare(
+    solutions: &[&'static Entry],
+    input: &str,
+    source: &str,
+    options: &Options,
+) -> Option<Disagreement> {
+    let first = solutions.first()?;
+    if !disagree(solutions, input, options) {
+        return None;
+    }
+
+    let input = minimize(input, |input| disagree(solutions, input, options));
+    Some(Disagreement {
+        year: first.year,
+        day: first.day,
+        part: first.part,
+        source: source.to_string(),
+        results: solutions
+            .iter()
+            .copied()
+            .zip(results(solutions, &input, options))
+            .collect(),
+        input,
+    })
+}
+
+/// Compares the variants of each part of `day` that has any, on every
+/// `(source, input)` pair
+pub fn compare_day(
+    year: u32,
+    day: u32,
+    inputs: &[(String, String)],
+    options: &Options,
+) -> Vec<Disagreement> {
+    let mut disagreements = vec![];
+
+    for part in [1, 2] {
+        let solutions = SOLUTIONS
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .collect::<Vec<_>>();
+        if solutions.len() < 2 {
+            continue;
+        }
+
+        for (source, input) in inputs {
+            disagreements.extend(compare(&solutions, input, source, options));
+        }
+    }
+
+    disagreements
+}
+
+/// `count` random inputs for `day` from consecutive seeds starting at `seed`,
+/// none if the day has no generator
+pub fn generated(year: u32, day: u32, seed: u64, count: u64, size: usize) -> Vec<(String, String)> {
+    let Some(solution) = SOLUTIONS.iter().find(|s| s.year == year && s.day == day) else {
+        return vec![];
+    };
+
+    (seed..seed + count)
+        .map_while(|seed| {
+            let input = solution.generate(&mut Rng::with_seed(seed), size)?;
+            Some((format!("generated input (seed {seed}, size {size})"), input))
+        })
+        .collect()
+}
+
+/// Whether `year` and `day` have a part with more than one solution
+pub fn has_variants(year: u32, day: u32) -> bool {
+    SOLUTIONS
+        .iter()
+        .any(|s| s.year == year && s.day == day && s.variant.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        runner::{self, entries},
+        testing::Numbers,
+    };
+
+    #[test]
+    fn minimizes_disagreements() {
+        let entries = Box::leak(Box::new(entries::<Numbers>()));
+        let part1 = entries.iter().filter(|e| e.part == 1).collect::<Vec<_>>();
+
+        assert!(compare(&part1, "1\n2\n3\n", "test", &Options::default()).is_none());
+
+        let disagreement = compare(
+            &part1,
+            "1\n2\n13\n4\n5\n13\n6\n",
+            "test",
+            &Options::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            disagreement.to_string(),
+            "\
+0 Day 0 - Part 1: solutions disagree on test
+  0 Day 0 - Part 1: 13
+  0 Day 0 - Part 1 - unlucky: 0
+minimized input:
+13
+"
+        );
+    }
+
+    #[test]
+    fn generators() {
+        let mut generators = 0;
+        for day in runner::days(2024) {
+            for (source, input) in generated(2024, day, 0, 2, 20) {
+                generators += 1;
+                for (solution, result) in run_all(
+                    runner::find(2024, day, None, None),
+                    &input,
+                    &Options::default(),
+                ) {
+                    assert!(result.is_ok(), "{solution} on {source}:\n{input}");
+                }
+            }
+        }
+
+        assert_eq!(generators, 2 * 7);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        for day in 1..=25 {
+            if has_variants(2024, day) {
+                // day 14 would look for the tree for 100_000 seconds
+                let options = if day == 14 {
+                    Options {
+                        params: "max_seconds = 200".parse().unwrap(),
+                        ..Options::default()
+                    }
+                } else {
+                    Options::default()
+                };
+
+                let inputs = generated(2024, day, 0, 20, 50);
+                let disagreements = compare_day(2024, day, &inputs, &options);
+                assert!(disagreements.is_empty(), "{}", disagreements[0]);
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+pub type Result<T, E = ParseError> = std::result::Result<T, E>;
+
+/// Malformed puzzle input. Lines and columns are 1-based, columns count chars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(day: u32, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found: None,
+        }
+    }
+
+    /// Error at byte `offset` of `input`, reporting the char found there
+    pub fn at(day: u32, input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let (line, column) = position(&input[..offset]);
+        let found = input[offset..].chars().next();
+
+        ParseError::new(day, line, column, expected)
+            .found(found.map_or(String::new(), |c| c.escape_debug().to_string()))
+    }
+
+    pub fn found(mut self, found: impl Into<String>) -> Self {
+        self.found = Some(found.into());
+        self
+    }
+}
+
+/// (line, column) right after `text`, if `text` starts at line 1, column 1
+pub(crate) fn position(text: &str) -> (usize, usize) {
+    let line = text.matches('\n').count() + 1;
+    let line_start = text.rfind('\n').map_or(0, |i| i + 1);
+
+    (line, text[line_start..].chars().count() + 1)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+
+        match self.found.as_deref() {
+            Some("") => write!(f, ", found nothing"),
+            Some(found) => write!(f, ", found `{found}`"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An input without problems that a solution still has no answer for, e.g. a
+/// day 17 program that can't output itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoAnswer {
+    pub day: u32,
+    pub reason: String,
+}
+
+impl NoAnswer {
+    pub fn new(day: u32, reason: impl Into<String>) -> Self {
+        NoAnswer {
+            day,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}: no answer, {}", self.day, self.reason)
+    }
+}
+
+impl std::error::Error for NoAnswer {}
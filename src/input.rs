@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+/// The layout every solution can rely on: no byte order mark, `\n` line
+/// endings and exactly one trailing newline, unless the input is empty
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let body = input.trim_end_matches(['\r', '\n']);
+
+    if body.is_empty() {
+        Cow::Borrowed("")
+    } else if !body.contains('\r') && input.len() == body.len() + 1 {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(body.replace("\r\n", "\n") + "\n")
+    }
+}
+
+/// Differently laid out copies of `input` that all normalize to the same text
+pub fn layouts(input: &str) -> Vec<(&'static str, String)> {
+    let lf = normalize(input).into_owned();
+    let body = lf.trim_end_matches('\n');
+
+    vec![
+        ("lf", lf.clone()),
+        ("crlf", lf.replace('\n', "\r\n")),
+        ("no trailing newline", body.to_string()),
+        ("crlf, no trailing newline", body.replace('\n', "\r\n")),
+        ("extra trailing newlines", format!("{body}\n\n\n")),
+        ("bom", format!("\u{feff}{lf}")),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes() {
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert_eq!(normalize("\r\n"), "");
+
+        for (layout, input) in layouts("a\n\nb\n") {
+            assert_eq!(normalize(&input), "a\n\nb\n", "{layout}");
+        }
+    }
+}
@@ -1,28 +1,26 @@
-#![feature(portable_simd)]
-#![feature(binary_heap_into_iter_sorted)]
-#![feature(iter_array_chunks)]
-#![feature(iter_map_windows)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+#![cfg_attr(feature = "nightly", feature(iter_array_chunks))]
 
-use aoc_runner_derive::aoc_lib;
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod differential;
+pub mod error;
+pub mod input;
+pub mod memory;
+mod parse;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submissions;
+#[cfg(test)]
+mod testing;
+pub mod utils;
+mod validate;
+pub mod watch;
 
-mod utils;
-
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-
-aoc_lib! {year = 2024}
+pub mod year2024;
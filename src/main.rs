@@ -1,6 +1,757 @@
-use advent_of_code_2024::day2;
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    process::{self, ExitCode},
+    thread,
+    time::{Duration, Instant},
+};
 
-fn main() {
-    let input = include_str!("../input/2024/day2.txt");
-    println!("{}", day2::part2(input));
+use advent_of_code::{
+    answers::{self, Ledger},
+    batch,
+    bench::{self, History},
+    cache::{self, AnswerCache},
+    client::{self, Client, Verdict},
+    config::{self, Config},
+    differential,
+    memory::{self, Counting},
+    report::{self, BenchRecord, RecordWriter, RunRecord},
+    runner::{self, Day, RunError, SOLUTIONS},
+    scaffold,
+    submissions::{Submission, SubmissionLog},
+    watch::{self, Snapshot},
+};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Defaults to `year` in .aoc.toml, or else to the latest year for
+    /// commands about a single day and to every year for the others
+    #[arg(long, global = true)]
+    year: Option<u32>,
+    /// Give up on a solution that takes longer than this many seconds, for
+    /// commands that run solutions on real inputs. Replaces the timeouts in
+    /// .aoc.toml.
+    #[arg(long, global = true, value_parser = config::parse_seconds)]
+    timeout: Option<f64>,
+    /// Directory with an <YEAR>/day<DAY>.txt layout [default: `inputs` in
+    /// .aoc.toml, or else input]
+    #[arg(long, global = true)]
+    inputs: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for a single day
+    Run {
+        day: u32,
+        #[arg(value_enum, default_value = "all")]
+        part: Part,
+        /// Run a named variant (e.g. `naive`) instead of the default solution
+        /// [default: the day's `variant` in .aoc.toml]
+        #[arg(short, long)]
+        variant: Option<String>,
+        /// Input file, or `-` to read from stdin [default: <INPUTS>/<YEAR>/day<DAY>.txt]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+        /// Count allocations, bytes allocated and peak memory of each part.
+        /// Implies --force.
+        #[arg(long)]
+        alloc: bool,
+        /// Run even the solutions with an answer in the cache
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run every registered solution in parallel and print a summary table
+    RunAll {
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+        /// Count allocations of each part, running one solution at a time.
+        /// Implies --force.
+        #[arg(long)]
+        alloc: bool,
+        /// Run even the solutions with an answer in the cache
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run every solution and variant of a day on each input in a directory,
+    /// checking the answers in <NAME>.answers.txt next to <NAME>.txt, in the
+    /// format of the answer ledger
+    Batch { day: u32, dir: PathBuf },
+    /// List all registered solutions
+    List,
+    /// Run every solution on its real input and compare with the answer ledger
+    /// in <YEAR>/answers.txt under the input directory
+    Check {
+        /// Instead compare the answers for CRLF, no trailing newline and other
+        /// layouts of each input with those for the normalized input
+        #[arg(long)]
+        layouts: bool,
+    },
+    /// Run all variants of each part on the real input and on generated inputs,
+    /// and report the minimized input wherever they disagree
+    Compare {
+        /// Only compare this day
+        day: Option<u32>,
+        /// Number of generated inputs per day
+        #[arg(short = 'n', long, default_value_t = 100)]
+        generated: u64,
+        /// Seed of the first generated input, the others use the following ones
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of each generated input, see `generate`
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Rebuild and re-run a day's example tests and real input whenever its
+    /// source file or input changes, and show how the answers and timings changed
+    Watch {
+        day: u32,
+        /// How often to check for changes, in seconds
+        #[arg(long, default_value_t = 1., value_parser = config::parse_seconds)]
+        interval: f64,
+    },
+    /// Print a random input for a day, for stress tests
+    Generate {
+        day: u32,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// What this means depends on the day, e.g. the number of lines or the
+        /// side of a map
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Time every solution and variant, and compare with the previous run
+    Bench {
+        /// Only benchmark this day
+        day: Option<u32>,
+        #[arg(value_enum, default_value = "all")]
+        part: Part,
+        /// Maximum number of runs per solution
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: usize,
+        /// Stop repeating a solution after this many seconds
+        #[arg(long, default_value_t = 5., value_parser = config::parse_seconds)]
+        budget: f64,
+        #[arg(long, default_value = "bench_history.tsv")]
+        history: PathBuf,
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+    },
+    /// Start a new day: its module under src/year<YEAR>, registered in the year,
+    /// and an empty input. Never overwrites anything.
+    Scaffold { day: u32 },
+    /// Download puzzle inputs that are not cached under input/<year> yet
+    Download {
+        #[arg(required = true)]
+        days: Vec<u32>,
+        /// Defaults to $AOC_BASE_URL, or else the real site
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit an answer, skipping answers the submission log already rules out
+    Submit {
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Defaults to the answer of the day's solution on its input
+        answer: Option<String>,
+        /// Defaults to $AOC_BASE_URL, or else the real site
+        #[arg(long)]
+        base_url: Option<String>,
+        #[arg(long, default_value = "submissions.tsv")]
+        log: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    All,
+}
+
+impl Part {
+    fn number(self) -> Option<u32> {
+        match self {
+            Part::One => Some(1),
+            Part::Two => Some(2),
+            Part::All => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per line
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Writer for the structured formats, `None` for text
+    fn writer(self) -> Option<RecordWriter<io::Stdout>> {
+        match self {
+            Format::Text => None,
+            Format::Json => Some(RecordWriter::json(io::stdout())),
+            Format::Csv => Some(RecordWriter::csv(io::stdout())),
+        }
+    }
+}
+
+fn read_input(config: &Config, year: u32, day: u32, path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(client::input_path(config.inputs(), year, day)),
+    }
+}
+
+/// The puzzle input of `day` from the inputs directory, as an error of its
+/// own so that commands over many days can report it and go on
+fn day_input(config: &Config, year: u32, day: u32) -> Result<String, RunError> {
+    let path = client::input_path(config.inputs(), year, day);
+    fs::read_to_string(&path).map_err(|e| RunError::Input(format!("{}: {e}", path.display())))
+}
+
+fn load_cache() -> Result<AnswerCache, Box<dyn Error>> {
+    Ok(AnswerCache::load(cache::FILE, cache::build_hash()?)?)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run(
+    config: &Config,
+    year: u32,
+    day: u32,
+    part: Part,
+    variant: Option<String>,
+    input: Option<PathBuf>,
+    format: Format,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let solutions = match variant.as_deref() {
+        Some(variant) => runner::find(year, day, part.number(), Some(variant)).collect(),
+        None => runner::find_preferring(year, day, part.number(), config.variant(year, day))
+            .collect::<Vec<_>>(),
+    };
+    if solutions.is_empty() {
+        return Err(format!("no solution registered for {year} day {day}").into());
+    }
+
+    let input = read_input(config, year, day, input)?;
+
+    let day = Day {
+        entries: solutions.clone(),
+        input,
+        options: config.options(year, day),
+    };
+    let results = load_cache()?.run(vec![day], force, |days| {
+        runner::run_all(
+            days[0].entries.iter().copied(),
+            &days[0].input,
+            &days[0].options,
+        )
+    })?;
+
+    // structured output reports errors per solution instead of failing
+    if let Some(mut writer) = format.writer() {
+        for (solution, result) in &results {
+            writer.write(&RunRecord::new(solution, result))?;
+        }
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for (solution, result) in results {
+        let timed = match result {
+            Ok(timed) => timed,
+            Err(e) => {
+                println!("{solution}: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        if timed.cached {
+            println!("{solution}: {} (cached)", timed.answer);
+            continue;
+        }
+        print!(
+            "{solution}: {} (parse {:?}, solve {:?}",
+            timed.answer, timed.parse, timed.solve
+        );
+        match timed.allocs {
+            Some(allocs) => println!(", {allocs})"),
+            None => println!(")"),
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} of {} solutions failed", solutions.len()).into());
+    }
+
+    Ok(())
+}
+
+fn run_all(
+    config: &Config,
+    years: Vec<u32>,
+    format: Format,
+    alloc: bool,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+
+    // a day without its input fails on its own, the others still run
+    let (mut days, mut missing) = (vec![], vec![]);
+    for year in years {
+        for day in runner::days(year) {
+            let entries = runner::SOLUTIONS
+                .iter()
+                .filter(|s| s.year == year && s.day == day);
+            match day_input(config, year, day) {
+                Ok(input) => days.push(Day {
+                    entries: entries.collect(),
+                    input,
+                    options: config.options(year, day),
+                }),
+                Err(e) => missing.extend(entries.map(|entry| (entry, Err(e.clone())))),
+            }
+        }
+    }
+
+    // allocations can only be told apart when one solution runs at a time
+    let results = load_cache()?.run(days, force || alloc, |days| {
+        if alloc {
+            memory::enable();
+            days.iter()
+                .flat_map(|day| {
+                    runner::run_all(day.entries.iter().copied(), &day.input, &day.options)
+                })
+                .collect()
+        } else {
+            runner::run_parallel(days)
+        }
+    })?;
+    let mut results = results.into_iter().chain(missing).collect::<Vec<_>>();
+    results.sort_by_key(|(solution, _)| (solution.year, solution.day));
+
+    match format.writer() {
+        Some(mut writer) => {
+            for (solution, result) in &results {
+                writer.write(&RunRecord::new(solution, result))?;
+            }
+        }
+        None => print!("{}", report::summary(&results, start.elapsed())),
+    }
+
+    let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{failed} of {} solutions failed", results.len()).into());
+    }
+
+    Ok(())
+}
+
+fn batch(config: &Config, year: u32, day: u32, dir: PathBuf) -> Result<(), Box<dyn Error>> {
+    let results = batch::run(year, day, &dir, &config.options(year, day))?;
+    if results.is_empty() {
+        return Err(format!("no inputs for {year} day {day} in {}", dir.display()).into());
+    }
+    print!("{}", batch::summary(&results));
+
+    let wrong = results.iter().filter(|r| r.is_wrong()).count();
+    if wrong > 0 {
+        return Err(format!("{wrong} wrong or failed").into());
+    }
+
+    Ok(())
+}
+
+fn check(config: &Config, years: Vec<u32>, layouts: bool) -> Result<(), Box<dyn Error>> {
+    let mut mismatches = vec![];
+    for &year in &years {
+        if layouts {
+            mismatches.extend(answers::check_layouts(year, config));
+        } else {
+            let ledger_path = answers::ledger_path(config.inputs(), year);
+            mismatches.extend(answers::check(&Ledger::load(ledger_path)?, year, config));
+        }
+    }
+
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+
+    let total = SOLUTIONS.iter().filter(|s| years.contains(&s.year)).count();
+    if !mismatches.is_empty() {
+        return Err(format!("{} of {total} solutions did not match", mismatches.len()).into());
+    }
+
+    println!("all {total} solutions match");
+    Ok(())
+}
+
+fn compare(
+    config: &Config,
+    years: Vec<u32>,
+    day: Option<u32>,
+    generated: u64,
+    seed: u64,
+    size: usize,
+) -> Result<(), Box<dyn Error>> {
+    let (mut disagreements, mut missing) = (vec![], vec![]);
+    let mut compared = 0;
+    for year in years {
+        for d in runner::days(year) {
+            if day.is_some_and(|day| day != d) || !differential::has_variants(year, d) {
+                continue;
+            }
+
+            // without its input a day is still compared on generated ones
+            let mut inputs = vec![];
+            match day_input(config, year, d) {
+                Ok(input) => {
+                    let path = client::input_path(config.inputs(), year, d);
+                    inputs.push((path.display().to_string(), input));
+                }
+                Err(e) => missing.push(format!("{year} day {d}: {e}")),
+            }
+            inputs.extend(differential::generated(year, d, seed, generated, size));
+
+            compared += inputs.len();
+            let options = config.options(year, d);
+            disagreements.extend(differential::compare_day(year, d, &inputs, &options));
+        }
+    }
+
+    for disagreement in &disagreements {
+        println!("{disagreement}");
+    }
+    for missing in &missing {
+        println!("{missing}");
+    }
+
+    if !disagreements.is_empty() {
+        return Err(format!("{} disagreements", disagreements.len()).into());
+    }
+    if !missing.is_empty() {
+        return Err(format!("no input for {} of the days", missing.len()).into());
+    }
+
+    println!("all variants agree on {compared} inputs");
+    Ok(())
+}
+
+fn watch(config: &Config, year: u32, day: u32, interval: f64) -> Result<(), Box<dyn Error>> {
+    let files = watch::watched_files(config.inputs(), year, day);
+    let mut last_modified = None;
+    let mut previous = Snapshot::default();
+
+    loop {
+        let modified = watch::last_modified(&files);
+        if modified == last_modified {
+            thread::sleep(Duration::from_secs_f64(interval));
+            continue;
+        }
+        last_modified = modified;
+
+        println!("=== {year} day {day} ===");
+
+        // the source may have changed, so this binary is out of date
+        let tests = process::Command::new("cargo")
+            .args(["test", "--quiet", "--lib", "--"])
+            .arg(format!("year{year}::day{day}::"))
+            .status()?;
+        if !tests.success() {
+            println!("example tests failed");
+            continue;
+        }
+
+        let run = process::Command::new("cargo")
+            .args(["run", "--release", "--quiet", "--", "run"])
+            .arg(day.to_string())
+            .arg("--year")
+            .arg(year.to_string())
+            .arg("--input")
+            .arg(&files[1])
+            .args(["--format", "json", "--force"])
+            .output()?;
+        if !run.status.success() {
+            print!("{}", String::from_utf8_lossy(&run.stderr));
+            continue;
+        }
+
+        let next = Snapshot::from_json_lines(&String::from_utf8_lossy(&run.stdout))?;
+        print!("{}", watch::diff(&previous, &next));
+        previous = next;
+    }
+}
+
+fn generate(year: u32, day: u32, seed: u64, size: usize) -> Result<(), Box<dyn Error>> {
+    let (_, input) = differential::generated(year, day, seed, 1, size)
+        .pop()
+        .ok_or(format!("no generator for {year} day {day}"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn new_day(config: &Config, year: u32, day: u32) -> Result<(), Box<dyn Error>> {
+    for path in scaffold::scaffold("src", config.inputs(), year, day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bench(
+    config: &Config,
+    years: Vec<u32>,
+    day: Option<u32>,
+    part: Part,
+    iterations: usize,
+    budget: f64,
+    history_path: PathBuf,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let history = History::load(&history_path)?;
+    let mut writer = format.writer();
+    let budget = Duration::from_secs_f64(budget);
+
+    let solutions = SOLUTIONS.iter().filter(|s| {
+        years.contains(&s.year)
+            && day.is_none_or(|d| s.day == d)
+            && part.number().is_none_or(|p| s.part == p)
+    });
+
+    let (mut benched, mut failed) = (0, 0);
+    for solution in solutions {
+        let options = config.options(solution.year, solution.day);
+        let stats = day_input(config, solution.year, solution.day)
+            .and_then(|input| bench::bench(solution, &input, &options, iterations, budget));
+        benched += 1;
+
+        let previous = history.previous(solution);
+        if let Some(writer) = &mut writer {
+            writer.write(&BenchRecord::new(solution, &stats, previous))?;
+        }
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(e) => {
+                if writer.is_none() {
+                    println!("{solution}: {e}");
+                }
+                failed += 1;
+                continue;
+            }
+        };
+        if writer.is_none() {
+            match previous {
+                Some(previous) => println!(
+                    "{solution}: {stats} [{} vs previous]",
+                    bench::change(previous, stats.median)
+                ),
+                None => println!("{solution}: {stats}"),
+            }
+        }
+
+        History::append(&history_path, solution, &stats)?;
+    }
+
+    if failed > 0 {
+        return Err(format!("{failed} of {benched} solutions failed").into());
+    }
+
+    Ok(())
+}
+
+fn download(
+    config: &Config,
+    year: u32,
+    days: Vec<u32>,
+    base_url: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let base_url = base_url.unwrap_or_else(client::base_url_from_env);
+    let mut client = Client::new(base_url, config.session()?);
+
+    for day in days {
+        match client::download(&mut client, config.inputs(), year, day)? {
+            (path, true) => println!("day {day}: downloaded to {}", path.display()),
+            (path, false) => println!("day {day}: cached at {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(
+    config: &Config,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: Option<String>,
+    base_url: Option<String>,
+    log: PathBuf,
+) -> Result<(), Box<dyn Error>> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = runner::find(year, day, Some(part), None)
+                .next()
+                .ok_or(format!(
+                    "no solution registered for {year} day {day} part {part}"
+                ))?;
+            let input = read_input(config, year, day, None)?;
+            solution
+                .run(&input, &config.options(year, day).params)?
+                .to_string()
+        }
+    };
+
+    let mut log = SubmissionLog::load(log)?;
+    if let Some(reason) = log.reject(year, day, part, &answer) {
+        return Err(format!("not submitting: {reason}").into());
+    }
+
+    let base_url = base_url.unwrap_or_else(client::base_url_from_env);
+    let mut client = Client::new(base_url, config.session()?);
+    let verdict = client.submit_answer(year, day, part, &answer)?;
+
+    log.record(Submission {
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+        verdict,
+    })?;
+
+    match verdict {
+        Verdict::Correct => {
+            println!("{answer} is correct");
+            let ledger = answers::ledger_path(config.inputs(), year);
+            if Ledger::record(&ledger, day, part, &answer)? {
+                println!("added to {}", ledger.display());
+            }
+        }
+        Verdict::TooHigh => println!("{answer} is too high"),
+        Verdict::TooLow => println!("{answer} is too low"),
+        Verdict::Wrong => println!("{answer} is wrong"),
+        Verdict::Wait(d) => println!("submitted too soon, wait {}s", d.as_secs()),
+        Verdict::AlreadySolved => println!("day {day} part {part} was already solved"),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut config = match Config::load(config::FILE) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: reading {}: {e}", config::FILE);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(timeout) = cli.timeout {
+        config.set_timeout(timeout);
+    }
+    if let Some(inputs) = cli.inputs {
+        config.inputs = Some(inputs);
+    }
+    let config = &config;
+
+    let cli_year = cli.year.or(config.year);
+    let year = cli_year.unwrap_or_else(runner::latest_year);
+    let years = cli_year.map_or_else(runner::years, |year| vec![year]);
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            variant,
+            input,
+            format,
+            alloc,
+            force,
+        } => {
+            if alloc {
+                memory::enable();
+            }
+            run(
+                config,
+                year,
+                day,
+                part,
+                variant,
+                input,
+                format,
+                force || alloc,
+            )
+        }
+        Command::RunAll {
+            format,
+            alloc,
+            force,
+        } => run_all(config, years, format, alloc, force),
+        Command::Batch { day, dir } => batch(config, year, day, dir),
+        Command::List => {
+            SOLUTIONS
+                .iter()
+                .filter(|s| years.contains(&s.year))
+                .for_each(|s| println!("{s}"));
+            Ok(())
+        }
+        Command::Check { layouts } => check(config, years, layouts),
+        Command::Compare {
+            day,
+            generated,
+            seed,
+            size,
+        } => compare(config, years, day, generated, seed, size),
+        Command::Watch { day, interval } => watch(config, year, day, interval),
+        Command::Generate { day, seed, size } => generate(year, day, seed, size),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            budget,
+            history,
+            format,
+        } => bench(
+            config, years, day, part, iterations, budget, history, format,
+        ),
+        Command::Scaffold { day } => new_day(config, year, day),
+        Command::Download { days, base_url } => download(config, year, days, base_url),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+            log,
+        } => submit(config, year, day, part, answer, base_url, log),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,151 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed},
+};
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+/// Can go below zero when memory from before counting was enabled is freed
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// The system allocator, counting allocations once `enable` has been called.
+/// Only binaries that install it as their `#[global_allocator]` can measure.
+pub struct Counting;
+
+impl Counting {
+    fn record(&self, allocated: usize, freed: usize) {
+        if !ENABLED.load(Relaxed) {
+            return;
+        }
+
+        if allocated > 0 {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(allocated as u64, Relaxed);
+        }
+        let live = LIVE.fetch_add(allocated as i64 - freed as i64, Relaxed);
+        PEAK.fetch_max(live + allocated as i64 - freed as i64, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Starts counting, there is no way back since it's meant for a whole run
+pub fn enable() {
+    ENABLED.store(true, Relaxed);
+}
+
+/// What a piece of code allocated. The counters are global, so this is only
+/// accurate if nothing else runs at the same time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    /// The most memory that was allocated but not yet freed at any point
+    pub peak: u64,
+}
+
+impl Display for Allocs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, peak {}",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `f` and counts its allocations, if counting is enabled
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    if !ENABLED.load(Relaxed) {
+        return (f(), None);
+    }
+
+    let (count, bytes, live) = (COUNT.load(Relaxed), BYTES.load(Relaxed), LIVE.load(Relaxed));
+    PEAK.store(live, Relaxed);
+
+    let result = f();
+
+    let allocs = Allocs {
+        count: COUNT.load(Relaxed) - count,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: (PEAK.load(Relaxed) - live).max(0) as u64,
+    };
+    (result, Some(allocs))
+}
+
+/// e.g. `512 B` or `1.50 MiB`
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.;
+    for unit in ["KiB", "MiB"] {
+        if size < 1024. {
+            return format!("{size:.2} {unit}");
+        }
+        size /= 1024.;
+    }
+
+    format!("{size:.2} GiB")
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        enable();
+
+        // other tests may allocate at the same time, so only check lower bounds
+        let (_, allocs) = measure(|| {
+            let mut v = Vec::<u8>::with_capacity(1000);
+            v.extend([1; 1000]);
+            drop(v);
+            vec![0u8; 500]
+        });
+        let allocs = allocs.unwrap();
+        assert!(allocs.count >= 2);
+        assert!(allocs.bytes >= 1500);
+        assert!(allocs.peak >= 1000);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+    }
+}
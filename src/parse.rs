@@ -0,0 +1,142 @@
+use std::{any::type_name, str::FromStr};
+
+use crate::error::{position, ParseError, Result};
+
+/// A piece of the puzzle input that remembers where it starts, so errors can
+/// point at the offending line and column
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    day: u32,
+    line: usize,
+    column: usize,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(day: u32, input: &'a str) -> Self {
+        Span {
+            day,
+            line: 1,
+            column: 1,
+            text: input,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Error at the start of this span, reporting (the first line of) its text
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = self.text.lines().next().unwrap_or_default();
+        ParseError::new(self.day, self.line, self.column, expected).found(found)
+    }
+
+    /// The span from byte `offset` onwards
+    pub fn skip(&self, offset: usize) -> Span<'a> {
+        let (line, column) = position(&self.text[..offset]);
+
+        Span {
+            day: self.day,
+            line: self.line + line - 1,
+            column: if line == 1 {
+                self.column + column - 1
+            } else {
+                column
+            },
+            text: &self.text[offset..],
+        }
+    }
+
+    /// Byte offset of `part`, which must be a slice of this span's text
+    fn offset_of(&self, part: &str) -> usize {
+        part.as_ptr() as usize - self.text.as_ptr() as usize
+    }
+
+    fn sub(&self, offset: usize, len: usize) -> Span<'a> {
+        let mut span = self.skip(offset);
+        span.text = &span.text[..len];
+        span
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>> {
+        if self.text.starts_with(prefix) {
+            Ok(self.skip(prefix.len()))
+        } else {
+            Err(self.error(format!("`{}`", prefix.escape_debug())))
+        }
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(Span<'a>, Span<'a>)> {
+        match self.text.find(delimiter) {
+            Some(i) => Ok((self.sub(0, i), self.skip(i + delimiter.len()))),
+            None => Err(self.error(format!("`{}`", delimiter.escape_debug()))),
+        }
+    }
+
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text
+            .split(delimiter)
+            .map(|part| self.sub(self.offset_of(part), part.len()))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text
+            .split_whitespace()
+            .map(|part| self.sub(self.offset_of(part), part.len()))
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text
+            .lines()
+            .map(|line| self.sub(self.offset_of(line), line.len()))
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        self.sub(start, self.text.trim().len())
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T> {
+        self.text
+            .parse()
+            .map_err(|_| self.error(format!("a value of type `{}`", type_name::<T>())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let input = Span::new(5, "47|53\n97|x\n\n75,47,61");
+        let (rules, updates) = input.split_once("\n\n").unwrap();
+
+        let bad = rules.lines().nth(1).unwrap().split_once("|").unwrap().1;
+        assert_eq!(
+            bad.parse::<i32>(),
+            Err(ParseError::new(5, 2, 4, "a value of type `i32`").found("x"))
+        );
+
+        let page = updates.split(",").nth(2).unwrap();
+        assert_eq!((page.line, page.column, page.as_str()), (4, 7, "61"));
+        assert_eq!(
+            page.strip_prefix("7").unwrap_err().to_string(),
+            "day 5, line 4, column 7: expected `7`, found `61`"
+        );
+    }
+
+    #[test]
+    fn error_at_offset() {
+        let input = "12 3\n4 5x";
+        assert_eq!(
+            ParseError::at(2, input, 8, "a digit").to_string(),
+            "day 2, line 2, column 4: expected a digit, found `x`"
+        );
+        assert_eq!(
+            ParseError::at(2, input, 9, "a digit").to_string(),
+            "day 2, line 2, column 5: expected a digit, found nothing"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use serde::Serialize;
+
+use crate::{
+    bench::Stats,
+    memory::format_bytes,
+    runner::{Answer, Entry, RunError, Timed},
+};
+
+/// One solution run, as written by `run --format json|csv`
+#[derive(Serialize)]
+pub struct RunRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<&'static str>,
+    pub answer: Option<Answer>,
+    /// `int` or `string`
+    pub answer_type: Option<&'static str>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    /// Only with allocation counting enabled
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+    pub error: Option<String>,
+    /// Taken from the answer cache, without times
+    pub cached: bool,
+}
+
+impl RunRecord {
+    pub fn new(solution: &Entry, result: &Result<Timed, RunError>) -> Self {
+        let (answer, parse, solve, error) = match result {
+            Ok(timed) if timed.cached => (Some(timed.answer.clone()), None, None, None),
+            Ok(timed) => (
+                Some(timed.answer.clone()),
+                Some(timed.parse.as_nanos()),
+                Some(timed.solve.as_nanos()),
+                None,
+            ),
+            Err(e) => (None, None, None, Some(e.to_string())),
+        };
+        let allocs = result.as_ref().ok().and_then(|timed| timed.allocs);
+
+        RunRecord {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            variant: solution.variant,
+            answer_type: answer.as_ref().map(Answer::kind),
+            answer,
+            parse_ns: parse,
+            solve_ns: solve,
+            allocations: allocs.map(|a| a.count),
+            allocated_bytes: allocs.map(|a| a.bytes),
+            peak_bytes: allocs.map(|a| a.peak),
+            error,
+            cached: result.as_ref().is_ok_and(|timed| timed.cached),
+        }
+    }
+}
+
+/// One benchmarked solution, as written by `bench --format json|csv`
+#[derive(Serialize)]
+pub struct BenchRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<&'static str>,
+    pub iterations: Option<usize>,
+    pub min_ns: Option<u128>,
+    pub median_ns: Option<u128>,
+    pub p95_ns: Option<u128>,
+    /// Input bytes per second, based on the median
+    pub throughput: Option<f64>,
+    pub previous_median_ns: Option<u128>,
+    pub error: Option<String>,
+}
+
+impl BenchRecord {
+    pub fn new(
+        solution: &Entry,
+        stats: &Result<Stats, RunError>,
+        previous: Option<Duration>,
+    ) -> Self {
+        let ok = stats.as_ref().ok();
+
+        BenchRecord {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            variant: solution.variant,
+            iterations: ok.map(|s| s.iterations),
+            min_ns: ok.map(|s| s.min.as_nanos()),
+            median_ns: ok.map(|s| s.median.as_nanos()),
+            p95_ns: ok.map(|s| s.p95.as_nanos()),
+            throughput: ok.map(Stats::throughput),
+            previous_median_ns: previous.map(|d| d.as_nanos()),
+            error: stats.as_ref().err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Writes records as JSON lines, or as CSV with a header row
+pub enum RecordWriter<W: Write> {
+    Json(W),
+    Csv(Box<csv::Writer<W>>),
+}
+
+impl<W: Write> RecordWriter<W> {
+    pub fn json(out: W) -> Self {
+        RecordWriter::Json(out)
+    }
+
+    pub fn csv(out: W) -> Self {
+        RecordWriter::Csv(Box::new(csv::Writer::from_writer(out)))
+    }
+
+    pub fn write(&mut self, record: &impl Serialize) -> io::Result<()> {
+        match self {
+            RecordWriter::Json(out) => {
+                serde_json::to_writer(&mut *out, record)?;
+                writeln!(out)?;
+                out.flush()
+            }
+            RecordWriter::Csv(out) => {
+                out.serialize(record).map_err(io::Error::other)?;
+                out.flush()
+            }
+        }
+    }
+}
+
+/// Left-aligned columns as wide as their widest cell, the first row is the
+/// header
+pub fn table(rows: &[Vec<String>]) -> String {
+    let Some(header) = rows.first() else {
+        return String::new();
+    };
+    let widths = (0..header.len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        table += line.trim_end();
+        table += "\n";
+    }
+
+    table
+}
+
+/// Table of all results sorted by day, part and variant, followed by the total
+/// runtime and the number of failures. Allocations get their own columns if
+/// they were counted.
+pub fn summary(results: &[(&Entry, Result<Timed, RunError>)], total: Duration) -> String {
+    let mut results = results.iter().collect::<Vec<_>>();
+    results.sort_by_key(|(s, _)| (s.year, s.day, s.part, s.variant));
+    let with_allocs = results
+        .iter()
+        .any(|(_, r)| r.as_ref().is_ok_and(|t| t.allocs.is_some()));
+
+    let mut header = vec!["year", "day", "part", "variant", "answer", "parse", "solve"];
+    if with_allocs {
+        header.extend(["allocations", "allocated", "peak"]);
+    }
+    let mut rows = vec![header.into_iter().map(String::from).collect::<Vec<_>>()];
+    for (solution, result) in &results {
+        let (answer, parse, solve) = match result {
+            Ok(timed) if timed.cached => (timed.answer.to_string(), "-".into(), "cached".into()),
+            Ok(timed) => (
+                timed.answer.to_string(),
+                format!("{:.2?}", timed.parse),
+                format!("{:.2?}", timed.solve),
+            ),
+            Err(e) => (e.to_string(), "-".to_string(), "-".to_string()),
+        };
+
+        let mut row = vec![
+            solution.year.to_string(),
+            solution.day.to_string(),
+            solution.part.to_string(),
+            solution.variant.unwrap_or("").to_string(),
+            answer,
+            parse,
+            solve,
+        ];
+        if with_allocs {
+            match result.as_ref().ok().and_then(|timed| timed.allocs) {
+                Some(allocs) => row.extend([
+                    allocs.count.to_string(),
+                    format_bytes(allocs.bytes),
+                    format_bytes(allocs.peak),
+                ]),
+                None => row.extend(["-", "-", "-"].map(String::from)),
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut table = table(&rows);
+    let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+    table += &format!(
+        "{} solutions, {failed} failed, total {total:.2?}\n",
+        results.len()
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::ParseError, memory::Allocs, runner};
+
+    fn records_input() -> Vec<(&'static Entry, Result<Timed, RunError>)> {
+        let timed = |answer| Timed {
+            answer,
+            parse: Duration::from_nanos(1500),
+            solve: Duration::from_nanos(20),
+            allocs: None,
+            cached: false,
+        };
+        let day17 = runner::find(2024, 17, Some(1), None).next().unwrap();
+        let day2 = runner::find(2024, 2, Some(1), Some("naive"))
+            .next()
+            .unwrap();
+
+        vec![
+            (day17, Ok(timed(Answer::Text("4,6,3".into())))),
+            (day2, Ok(timed(Answer::Int(572)))),
+            (
+                day2,
+                Err(RunError::Parse(
+                    ParseError::new(2, 3, 1, "a digit").found("x"),
+                )),
+            ),
+        ]
+    }
+
+    fn records() -> Vec<RunRecord> {
+        records_input()
+            .iter()
+            .map(|(solution, result)| RunRecord::new(solution, result))
+            .collect()
+    }
+
+    #[test]
+    fn json_lines() {
+        let mut writer = RecordWriter::json(vec![]);
+        for record in records() {
+            writer.write(&record).unwrap();
+        }
+
+        let RecordWriter::Json(out) = writer else {
+            unreachable!()
+        };
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"{"year":2024,"day":17,"part":1,"variant":null,"answer":"4,6,3","answer_type":"string","parse_ns":1500,"solve_ns":20,"allocations":null,"allocated_bytes":null,"peak_bytes":null,"error":null,"cached":false}
+{"year":2024,"day":2,"part":1,"variant":"naive","answer":572,"answer_type":"int","parse_ns":1500,"solve_ns":20,"allocations":null,"allocated_bytes":null,"peak_bytes":null,"error":null,"cached":false}
+{"year":2024,"day":2,"part":1,"variant":"naive","answer":null,"answer_type":null,"parse_ns":null,"solve_ns":null,"allocations":null,"allocated_bytes":null,"peak_bytes":null,"error":"day 2, line 3, column 1: expected a digit, found `x`","cached":false}
+"#
+        );
+    }
+
+    #[test]
+    fn summary_table() {
+        let results = records_input();
+        assert_eq!(
+            summary(&results, Duration::from_millis(1500)),
+            "\
+year  day  part  variant  answer                                                parse   solve
+2024  2    1     naive    572                                                   1.50µs  20.00ns
+2024  2    1     naive    day 2, line 3, column 1: expected a digit, found `x`  -       -
+2024  17   1              4,6,3                                                 1.50µs  20.00ns
+3 solutions, 1 failed, total 1.50s
+"
+        );
+    }
+
+    #[test]
+    fn summary_allocations() {
+        let mut results = records_input();
+        results.truncate(1);
+        if let Ok(timed) = &mut results[0].1 {
+            timed.allocs = Some(Allocs {
+                count: 3,
+                bytes: 2048,
+                peak: 1024,
+            });
+        }
+
+        assert_eq!(
+            summary(&results, Duration::from_millis(1)),
+            "\
+year  day  part  variant  answer  parse   solve    allocations  allocated  peak
+2024  17   1              4,6,3   1.50µs  20.00ns  3            2.00 KiB   1.00 KiB
+1 solutions, 0 failed, total 1.00ms
+"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let mut writer = RecordWriter::csv(vec![]);
+        for record in records() {
+            writer.write(&record).unwrap();
+        }
+
+        let RecordWriter::Csv(out) = writer else {
+            unreachable!()
+        };
+        assert_eq!(
+            String::from_utf8(out.into_inner().unwrap()).unwrap(),
+            r#"year,day,part,variant,answer,answer_type,parse_ns,solve_ns,allocations,allocated_bytes,peak_bytes,error,cached
+2024,17,1,,"4,6,3",string,1500,20,,,,,false
+2024,2,1,naive,572,int,1500,20,,,,,false
+2024,2,1,naive,,,,,,,,"day 2, line 3, column 1: expected a digit, found `x`",false
+"#
+        );
+    }
+}
@@ -0,0 +1,665 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    error::Error,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, LazyLock, Once},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    error::{NoAnswer, ParseError},
+    memory::Allocs,
+    solution::{Params, Solution, Solved, WithParams},
+    *,
+};
+
+/// A day's input after parsing, shared by all of the day's solutions
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+type ParseFn = fn(&str, &Params) -> Result<Parsed, RunError>;
+type SolveFn = Arc<dyn Fn(&Parsed) -> Solved + Send + Sync>;
+type GenerateFn = fn(&mut fastrand::Rng, usize) -> Option<String>;
+
+/// What a solution returns, most are numbers but e.g. day 17 part 1 is a string
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Text(_) => "string",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+            }
+        }
+    )*};
+}
+
+int_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// One part or variant of a registered `Solution`, with its input type erased
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<&'static str>,
+    parse: ParseFn,
+    solve: SolveFn,
+    generate: GenerateFn,
+}
+
+impl Entry {
+    /// The input is normalized first, see `input::normalize`
+    pub fn parse(&self, input: &str, params: &Params) -> Result<Parsed, RunError> {
+        (self.parse)(&input::normalize(input), params)
+    }
+
+    /// `parsed` has to come from an entry of the same day
+    pub fn solve(&self, parsed: &Parsed) -> Result<Answer, RunError> {
+        Ok((self.solve)(parsed)?)
+    }
+
+    pub fn run(&self, input: &str, params: &Params) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.solve(&self.parse(input, params)?)?)
+    }
+
+    /// A random input for the entry's day, see `Solution::generate`
+    pub fn generate(&self, rng: &mut fastrand::Rng, size: usize) -> Option<String> {
+        (self.generate)(rng, size)
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {} - Part {}", self.year, self.day, self.part)?;
+        if let Some(variant) = self.variant {
+            write!(f, " - {variant}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `Err` with every problem `Solution::validate` finds, in order of position
+fn validate<S: Solution>(input: &str) -> Result<(), RunError> {
+    let mut errors = S::validate(input);
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    errors.sort_by_key(|e| (e.line, e.column));
+    Err(RunError::Invalid(errors))
+}
+
+/// Both parts and all variants of `S`, which takes no parameters
+pub fn entries<S: Solution>() -> Vec<Entry> {
+    entries_parsed_by::<S>(|input, params| {
+        if let Some(key) = params.keys().next() {
+            return Err(RunError::Params(format!("unknown parameter `{key}`")));
+        }
+        validate::<S>(input)?;
+        Ok(Box::new(S::parse(input)?))
+    })
+}
+
+/// Both parts and all variants of `S`, reading its parameters from the table
+/// passed to `Entry::parse`
+pub fn entries_with_params<S: WithParams>() -> Vec<Entry> {
+    entries_parsed_by::<S>(|input, params| {
+        let params = toml::Value::Table(params.clone())
+            .try_into()
+            .map_err(|e: toml::de::Error| RunError::Params(e.message().to_string()))?;
+        S::validate_params(&params).map_err(RunError::Params)?;
+        validate::<S>(input)?;
+        Ok(Box::new(S::parse_with(input, &params)?))
+    })
+}
+
+fn entries_parsed_by<S: Solution>(parse: ParseFn) -> Vec<Entry> {
+    let entry = |part, variant, solve: fn(&S::Input) -> Solved| Entry {
+        year: S::YEAR,
+        day: S::DAY,
+        part,
+        variant,
+        parse,
+        solve: Arc::new(move |parsed| solve(parsed.downcast_ref().unwrap())),
+        generate: S::generate,
+    };
+
+    let mut entries = vec![entry(1, None, S::part1), entry(2, None, S::part2)];
+    for variant in S::variants() {
+        entries.push(entry(variant.part, Some(variant.name), variant.solve));
+    }
+    entries.sort_by_key(|e| e.part);
+
+    entries
+}
+
+/// Every registered solution of every year, ordered by year and day
+pub static SOLUTIONS: LazyLock<Vec<Entry>> = LazyLock::new(year2024::solutions);
+
+/// All years with registered solutions, in ascending order
+pub fn years() -> Vec<u32> {
+    let mut years = SOLUTIONS.iter().map(|s| s.year).collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// The most recent year, used when none is given
+pub fn latest_year() -> u32 {
+    *years().last().unwrap()
+}
+
+/// All days of `year` with registered solutions, in ascending order
+pub fn days(year: u32) -> Vec<u32> {
+    let mut days = SOLUTIONS
+        .iter()
+        .filter(|s| s.year == year)
+        .map(|s| s.day)
+        .collect::<Vec<_>>();
+    days.dedup();
+    days
+}
+
+/// All solutions for `day` of `year`, optionally restricted to a single part
+/// and variant. Without a variant only the default (unnamed) solutions are returned.
+pub fn find(
+    year: u32,
+    day: u32,
+    part: Option<u32>,
+    variant: Option<&str>,
+) -> impl Iterator<Item = &'static Entry> + '_ {
+    SOLUTIONS.iter().filter(move |s| {
+        s.year == year && s.day == day && part.is_none_or(|p| s.part == p) && s.variant == variant
+    })
+}
+
+/// The default solutions like `find`, but for each part that has `variant`
+/// that one instead, e.g. for the variant configured for a day
+pub fn find_preferring(
+    year: u32,
+    day: u32,
+    part: Option<u32>,
+    variant: Option<&str>,
+) -> impl Iterator<Item = &'static Entry> + '_ {
+    find(year, day, part, None).map(move |default| {
+        variant
+            .and_then(|variant| find(year, day, Some(default.part), Some(variant)).next())
+            .unwrap_or(default)
+    })
+}
+
+/// How to run a day's solutions, usually from `config::Config::options`
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Applies to parsing and to each solution separately, see `with_timeout`
+    pub timeout: Option<Duration>,
+    pub params: Params,
+}
+
+pub struct Timed {
+    pub answer: Answer,
+    /// Shared by all of the day's solutions, since they only parse once
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Allocations while solving, if counting is enabled, see `memory::enable`
+    pub allocs: Option<Allocs>,
+    /// Taken from `cache::AnswerCache` without running, so there are no times
+    pub cached: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    /// The input file couldn't be read
+    Input(String),
+    Parse(ParseError),
+    /// Everything that's wrong with the input, see `Solution::validate`
+    Invalid(Vec<ParseError>),
+    /// The day's parameters from the config file don't fit, see `WithParams`
+    Params(String),
+    NoAnswer(NoAnswer),
+    Panicked(String),
+    /// Didn't finish within the given time, see `run_all`
+    TimedOut(Duration),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Input(msg) => write!(f, "no input: {msg}"),
+            RunError::Parse(e) => write!(f, "{e}"),
+            RunError::Invalid(errors) => {
+                let errors = errors.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                write!(f, "invalid input: {}", errors.join("; "))
+            }
+            RunError::Params(msg) => write!(f, "invalid params: {msg}"),
+            RunError::NoAnswer(e) => write!(f, "{e}"),
+            RunError::Panicked(msg) => write!(f, "panicked: {msg}"),
+            RunError::TimedOut(timeout) => write!(f, "timed out after {timeout:.2?}"),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(e: ParseError) -> Self {
+        RunError::Parse(e)
+    }
+}
+
+impl From<NoAnswer> for RunError {
+    fn from(e: NoAnswer) -> Self {
+        RunError::NoAnswer(e)
+    }
+}
+
+thread_local! {
+    /// Whether this thread is running a solution inside `catch_panic`
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Replaces the panic hook by one that stays quiet for panics `catch_panic`
+/// reports anyway, so they don't end up between the rows of a table or the
+/// lines of JSON output. That includes every panic on a rayon worker, which
+/// rayon passes on to the solution or `run_parallel` waiting for it. Any other
+/// panic still goes to the previous hook.
+fn quiet_panics() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() && rayon::current_thread_index().is_none() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, RunError> {
+    quiet_panics();
+
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+
+    result.map_err(|payload| {
+        let msg = match payload.downcast::<String>() {
+            Ok(msg) => *msg,
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map_or("unknown panic".to_string(), |msg| msg.to_string()),
+        };
+        RunError::Panicked(msg)
+    })
+}
+
+/// Runs `f` on its own thread if there is a `timeout`, and gives up waiting
+/// for it after that. There is no way to stop the thread, so it keeps running
+/// in the background until the process exits.
+fn with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> Result<T, RunError> + Send + 'static,
+) -> Result<T, RunError> {
+    let Some(timeout) = timeout else {
+        return f();
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(f()));
+    receiver
+        .recv_timeout(timeout)
+        .map_err(|_| RunError::TimedOut(timeout))?
+}
+
+/// A day's input after parsing
+struct Shared {
+    time: Duration,
+    parsed: Result<Arc<Parsed>, RunError>,
+}
+
+impl Shared {
+    fn parse(entry: &Entry, input: &str, options: &Options) -> Self {
+        let timeout = options.timeout;
+        let (parse, input, params) = (entry.parse, input.to_string(), options.params.clone());
+        let parsed = with_timeout(timeout, move || {
+            let start = Instant::now();
+            let parsed = catch_panic(|| parse(&input::normalize(&input), &params))?;
+            Ok((parsed, start.elapsed()))
+        });
+
+        match parsed {
+            Ok((parsed, time)) => Shared {
+                time,
+                parsed: parsed.map(Arc::new),
+            },
+            Err(e) => Shared {
+                time: timeout.unwrap_or_default(),
+                parsed: Err(e),
+            },
+        }
+    }
+
+    fn solve(&self, entry: &Entry, timeout: Option<Duration>) -> Result<Timed, RunError> {
+        let (parsed, solve) = (self.parsed.clone()?, entry.solve.clone());
+
+        let (answer, time, allocs) = with_timeout(timeout, move || {
+            let start = Instant::now();
+            let (answer, allocs) = memory::measure(|| catch_panic(|| solve(&parsed)));
+            Ok((answer??, start.elapsed(), allocs))
+        })?;
+        Ok(Timed {
+            answer,
+            parse: self.time,
+            solve: time,
+            allocs,
+            cached: false,
+        })
+    }
+}
+
+/// Runs `entries`, which all belong to the same day, on `input`, parsing it
+/// only once. A panicking solution doesn't take the others down, and with a
+/// timeout neither does one that runs away.
+pub fn run_all<'a>(
+    entries: impl IntoIterator<Item = &'a Entry>,
+    input: &str,
+    options: &Options,
+) -> Vec<(&'a Entry, Result<Timed, RunError>)> {
+    let mut shared = None;
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let shared = shared.get_or_insert_with(|| Shared::parse(entry, input, options));
+            (entry, shared.solve(entry, options.timeout))
+        })
+        .collect()
+}
+
+/// A day's solutions with their input, for `run_parallel`
+pub struct Day<'a> {
+    pub entries: Vec<&'a Entry>,
+    pub input: String,
+    pub options: Options,
+}
+
+/// Like `run_all` for several days with their inputs, running the days and
+/// then their solutions on a thread pool of their own. Results keep the given
+/// order. Allocation counts are meaningless here, since solutions run
+/// concurrently.
+///
+/// With a timeout each solution runs on its own thread while a worker of the
+/// pool waits for it, so solutions that use rayon themselves get the global
+/// pool, which those waiting workers would otherwise starve.
+pub fn run_parallel<'a>(days: &[Day<'a>]) -> Vec<(&'a Entry, Result<Timed, RunError>)> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .thread_name(|i| format!("run-parallel-{i}"))
+        .build()
+        .expect("a thread pool for the days");
+
+    pool.install(|| {
+        days.par_iter()
+            .flat_map(|day| {
+                let Some(first) = day.entries.first() else {
+                    return vec![];
+                };
+                let shared = Shared::parse(first, &day.input, &day.options);
+
+                day.entries
+                    .par_iter()
+                    .map(|&entry| (entry, shared.solve(entry, day.options.timeout)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solution::Variant, testing::Numbers};
+
+    #[test]
+    fn registers_variants() {
+        let entries = entries::<Numbers>();
+        let names = entries.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "0 Day 0 - Part 1",
+                "0 Day 0 - Part 1 - unlucky",
+                "0 Day 0 - Part 2"
+            ]
+        );
+
+        let answers = run_all(&entries, "3\n13\n", &Options::default())
+            .into_iter()
+            .map(|(_, r)| r.unwrap().answer)
+            .collect::<Vec<_>>();
+        assert_eq!(answers, [Answer::Int(16), Answer::Int(3), Answer::Int(3)]);
+    }
+
+    #[test]
+    fn catches_panics() {
+        let entries = entries::<Numbers>();
+        for run in [
+            run_all(&entries, "", &Options::default()),
+            run_all(
+                &entries,
+                "",
+                &Options {
+                    timeout: Some(Duration::from_secs(10)),
+                    ..Options::default()
+                },
+            ),
+            run_parallel(&[Day {
+                entries: entries.iter().collect(),
+                input: String::new(),
+                options: Options::default(),
+            }]),
+        ] {
+            assert_eq!(
+                run[2].1.as_ref().err(),
+                Some(&RunError::Panicked(
+                    "index out of bounds: the len is 0 but the index is 0".to_string()
+                ))
+            );
+            assert_eq!(
+                run[0].1.as_ref().ok().map(|t| &t.answer),
+                Some(&Answer::Int(0))
+            );
+        }
+    }
+
+    struct Stuck;
+
+    impl Solution for Stuck {
+        const YEAR: u32 = 0;
+        const DAY: u32 = 1;
+
+        type Input = ();
+
+        fn parse(_: &str) -> error::Result<()> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Solved {
+            loop {
+                thread::sleep(Duration::from_secs(3600));
+            }
+        }
+
+        fn part2(_: &()) -> Solved {
+            Ok(1.into())
+        }
+    }
+
+    #[test]
+    fn times_out() {
+        let entries = entries::<Stuck>();
+        let timeout = Duration::from_millis(50);
+        let options = Options {
+            timeout: Some(timeout),
+            ..Options::default()
+        };
+        let run = run_all(&entries, "", &options);
+
+        assert_eq!(run[0].1.as_ref().err(), Some(&RunError::TimedOut(timeout)));
+        assert_eq!(
+            run[1].1.as_ref().ok().map(|t| &t.answer),
+            Some(&Answer::Int(1))
+        );
+        assert_eq!(
+            RunError::TimedOut(timeout).to_string(),
+            "timed out after 50.00ms"
+        );
+    }
+
+    /// Sums with rayon, in one variant for each thread of the global pool
+    struct Summed;
+
+    impl Solution for Summed {
+        const YEAR: u32 = 0;
+        const DAY: u32 = 2;
+
+        type Input = Vec<u64>;
+
+        fn parse(_: &str) -> error::Result<Vec<u64>> {
+            Ok((1..=1000).collect())
+        }
+
+        fn part1(numbers: &Vec<u64>) -> Solved {
+            Ok(numbers.par_iter().sum::<u64>().into())
+        }
+
+        fn part2(numbers: &Vec<u64>) -> Solved {
+            Ok(numbers.par_iter().max().copied().unwrap_or(0).into())
+        }
+
+        fn variants() -> Vec<Variant<Vec<u64>>> {
+            (0..rayon::current_num_threads())
+                .map(|_| Variant::new(1, "parallel", Self::part1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn runs_rayon_solutions_in_parallel_with_timeout() {
+        let entries = entries::<Summed>();
+        let run = run_parallel(&[Day {
+            entries: entries.iter().collect(),
+            input: String::new(),
+            options: Options {
+                timeout: Some(Duration::from_secs(10)),
+                ..Options::default()
+            },
+        }]);
+
+        assert_eq!(run.len(), entries.len());
+        for (entry, result) in run {
+            let expected = if entry.part == 1 { 500_500 } else { 1000 };
+            assert_eq!(result.map(|t| t.answer), Ok(Answer::Int(expected)));
+        }
+    }
+
+    #[test]
+    fn prefers_variants() {
+        let found = |part, variant| {
+            find_preferring(2024, 2, part, variant)
+                .map(|e| (e.part, e.variant))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(found(None, Some("naive")), [(1, Some("naive")), (2, None)]);
+        assert_eq!(found(Some(2), Some("naive")), [(2, None)]);
+        assert_eq!(found(None, None), [(1, None), (2, None)]);
+    }
+
+    #[test]
+    fn checks_params() {
+        let params = |s: &str| s.parse::<Params>().unwrap();
+        let day11 = find(2024, 11, Some(1), None).next().unwrap();
+        let day2 = find(2024, 2, Some(1), None).next().unwrap();
+        let day14 = find(2024, 14, Some(1), None).next().unwrap();
+
+        assert_eq!(
+            day11.run("125 17", &params("part1 = 6")).unwrap(),
+            Answer::Int(22)
+        );
+        assert_eq!(
+            day11.run("125 17", &Params::new()).unwrap(),
+            Answer::Int(55312)
+        );
+
+        let error = day11.parse("125 17", &params("part3 = 6")).err().unwrap();
+        assert!(matches!(error, RunError::Params(msg) if msg.contains("part3")));
+        let error = day11.parse("125 17", &params("part1 = -1")).err().unwrap();
+        assert!(matches!(error, RunError::Params(_)));
+        assert_eq!(
+            day2.parse("1 2", &params("rows = 7")).err(),
+            Some(RunError::Params("unknown parameter `rows`".into()))
+        );
+        assert_eq!(
+            day14.parse("p=0,4 v=3,-3", &params("rows = 0")).err(),
+            Some(RunError::Params("`rows` must be positive, not 0".into()))
+        );
+    }
+
+    #[test]
+    fn validates_inputs() {
+        let day6 = find(2024, 6, Some(1), None).next().unwrap();
+        let Err(RunError::Invalid(errors)) = day6.parse("..^.\n.#\n^...\n", &Params::new()) else {
+            panic!("expected invalid input");
+        };
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            [(2, 3), (3, 1)]
+        );
+
+        let day9 = find(2024, 9, Some(1), None).next().unwrap();
+        let error = day9.run("12\n34\n", &Params::new()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid input: day 9, line 2, column 1: expected a single line, found `34`"
+        );
+        assert!(day9.run("2333133121414131402", &Params::new()).is_ok());
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// A new day's module, with a line-based parser, both parts and example tests
+/// to fill in
+pub fn template(day: u32) -> String {
+    format!(
+        r#"use crate::{{
+    error::Result,
+    parse::Span,
+    solution::{{Solution, Solved}},
+}};
+
+const DAY: u32 = {day};
+
+fn parse(input: &str) -> Result<Vec<String>> {{
+    Ok(Span::new(DAY, input)
+        .lines()
+        .map(|line| line.as_str().to_string())
+        .collect())
+}}
+
+fn part1(lines: &[String]) -> usize {{
+    lines.len()
+}}
+
+fn part2(lines: &[String]) -> usize {{
+    lines.len()
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        parse(input)
+    }}
+
+    fn part1(input: &Self::Input) -> Solved {{
+        Ok(part1(input).into())
+    }}
+
+    fn part2(input: &Self::Input) -> Solved {{
+        Ok(part2(input).into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const TEST: &str = "";
+
+    #[test]
+    fn part1_example() {{
+        assert_eq!(part1(&parse(TEST).unwrap()), 0);
+    }}
+
+    #[test]
+    fn part2_example() {{
+        assert_eq!(part2(&parse(TEST).unwrap()), 0);
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `day` to a year module like `src/year2024.rs`: its `pub mod`, kept in
+/// the existing order, and its entries in `solutions`, kept in order of day.
+/// `None` if the day is already registered or the module looks different.
+pub fn register(year_module: &str, day: u32) -> Option<String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!("entries::<day{day}::Day{day}>(),");
+    let registered = year_module.lines().any(|line| entry_day(line) == Some(day));
+    if year_module.contains(&module) || registered {
+        return None;
+    }
+
+    let mut lines = year_module.lines().map(String::from).collect::<Vec<_>>();
+
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    // sorted by name, so without the `;` that would put day1 after day10
+    let at = modules
+        .iter()
+        .find(|&&i| lines[i].trim_end_matches(';') > module.trim_end_matches(';'))
+        .map_or(modules.last()? + 1, |&i| i);
+    lines.insert(at, module);
+
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, entry_day(line)?)))
+        .collect::<Vec<_>>();
+    let (last, _) = entries.last()?;
+    let at = entries
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    let indent = lines[*last].len() - lines[*last].trim_start().len();
+    lines.insert(at, format!("{}{entry}", " ".repeat(indent)));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// The day of an `entries::<dayN::DayN>(),` line, or of an
+/// `entries_with_params` one
+fn entry_day(line: &str) -> Option<u32> {
+    let line = line.trim().strip_prefix("entries")?;
+    line.strip_prefix("_with_params")
+        .unwrap_or(line)
+        .strip_prefix("::<day")?
+        .split_once("::")?
+        .0
+        .parse()
+        .ok()
+}
+
+/// Creates the module of a new day under `src`, registers it in its year, and
+/// creates an empty input under `inputs`. Existing files are left alone, but
+/// an existing module is an error. Returns the files that were written.
+pub fn scaffold(
+    src: impl AsRef<Path>,
+    inputs: impl AsRef<Path>,
+    year: u32,
+    day: u32,
+) -> io::Result<Vec<PathBuf>> {
+    let year_path = src.as_ref().join(format!("year{year}.rs"));
+    let day_path = src.as_ref().join(format!("year{year}/day{day}.rs"));
+    if day_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_path.display()),
+        ));
+    }
+
+    let year_module = fs::read_to_string(&year_path)?;
+    let registered = register(&year_module, day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("can't register day {day} in {}", year_path.display()),
+        )
+    })?;
+
+    fs::File::create_new(&day_path)?.write_all(template(day).as_bytes())?;
+    fs::write(&year_path, registered)?;
+    let mut written = vec![day_path, year_path];
+
+    let input_path = crate::client::input_path(inputs, year, day);
+    if !input_path.exists() {
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::File::create_new(&input_path)?;
+        written.push(input_path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "use crate::runner::{entries, Entry};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+pub fn solutions() -> Vec<Entry> {
+    [
+        entries::<day1::Day1>(),
+        entries::<day2::Day2>(),
+        entries_with_params::<day10::Day10>(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+";
+
+    #[test]
+    fn registers_days() {
+        assert_eq!(
+            register(YEAR_MODULE, 3).unwrap(),
+            "use crate::runner::{entries, Entry};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+
+pub fn solutions() -> Vec<Entry> {
+    [
+        entries::<day1::Day1>(),
+        entries::<day2::Day2>(),
+        entries::<day3::Day3>(),
+        entries_with_params::<day10::Day10>(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+"
+        );
+
+        let registered = register(YEAR_MODULE, 11).unwrap();
+        assert!(registered.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(registered.contains("Day10>(),\n        entries::<day11::Day11>(),\n    ]"));
+
+        assert_eq!(register(YEAR_MODULE, 2), None);
+        assert_eq!(register("", 2), None);
+    }
+
+    #[test]
+    fn scaffolds_without_clobbering() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let (src, inputs) = (dir.join("src"), dir.join("input"));
+        fs::create_dir_all(src.join("year2024")).unwrap();
+        fs::write(src.join("year2024.rs"), YEAR_MODULE).unwrap();
+
+        let written = scaffold(&src, &inputs, 2024, 3).unwrap();
+        assert_eq!(written.len(), 3);
+        assert!(fs::read_to_string(src.join("year2024/day3.rs"))
+            .unwrap()
+            .contains("pub struct Day3;"));
+        assert_eq!(
+            fs::read_to_string(inputs.join("2024/day3.txt")).unwrap(),
+            ""
+        );
+
+        // the input exists already, and the module too the second time
+        fs::write(inputs.join("2024/day4.txt"), "1 2 3\n").unwrap();
+        assert_eq!(scaffold(&src, &inputs, 2024, 4).unwrap().len(), 2);
+        assert_eq!(
+            fs::read_to_string(inputs.join("2024/day4.txt")).unwrap(),
+            "1 2 3\n"
+        );
+        let error = scaffold(&src, &inputs, 2024, 4).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+use fastrand::Rng;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::{NoAnswer, ParseError, Result},
+    runner::Answer,
+};
+
+/// A day's parameters from the config file, see `config::DayConfig` and
+/// `WithParams`
+pub type Params = toml::Table;
+
+/// What a part returns, an error only for inputs that have no answer
+pub type Solved = std::result::Result<Answer, NoAnswer>;
+
+/// A day's puzzle. The input is parsed once and shared by both parts and all
+/// variants. Register new days in their year's `solutions`, e.g.
+/// `year2024::solutions`, which `runner::SOLUTIONS` is built from.
+pub trait Solution: 'static {
+    const YEAR: u32;
+    const DAY: u32;
+
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Every problem with the structure of the input that `parse` or the
+    /// parts rely on, like a map without a start. The runner only parses
+    /// inputs without any.
+    fn validate(_input: &str) -> Vec<ParseError> {
+        vec![]
+    }
+
+    fn part1(input: &Self::Input) -> Solved;
+
+    fn part2(input: &Self::Input) -> Solved;
+
+    /// Alternative implementations of either part, selectable by name
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![]
+    }
+
+    /// A random puzzle input for stress tests and for comparing variants on
+    /// more than the real input. What `size` means depends on the day, e.g.
+    /// the number of lines or the side of a map.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// A day whose puzzle depends on more than the input, like the size of an
+/// area that differs between the example and the real puzzle. `parse` should
+/// use the real puzzle's parameters. Register these days with
+/// `runner::entries_with_params`.
+pub trait WithParams: Solution {
+    /// Read from the day's `params` in the config file, so missing fields
+    /// should have serde defaults
+    type Params: DeserializeOwned;
+
+    /// What's wrong with parameters that deserialized fine but that the day
+    /// can't work with, like an empty area
+    fn validate_params(_params: &Self::Params) -> std::result::Result<(), String> {
+        Ok(())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input>;
+}
+
+pub struct Variant<I> {
+    pub part: u32,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Solved,
+}
+
+impl<I> Variant<I> {
+    pub fn new(part: u32, name: &'static str, solve: fn(&I) -> Solved) -> Self {
+        Variant { part, name, solve }
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::Verdict;
+
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let fields = line.split('\t').collect::<Vec<_>>();
+    let [_, year, day, part, answer, verdict] = fields[..] else {
+        return None;
+    };
+
+    Some(Submission {
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        answer: answer.to_string(),
+        verdict: verdict.parse().ok()?,
+    })
+}
+
+/// Every answer ever submitted, one line per attempt:
+/// `timestamp year day part answer verdict`
+pub struct SubmissionLog {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = vec![];
+        for (i, line) in content.lines().enumerate() {
+            entries.push(parse_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed submission", path.display(), i + 1),
+                )
+            })?);
+        }
+
+        Ok(SubmissionLog { path, entries })
+    }
+
+    fn attempts(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Submission> {
+        self.entries
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+            .filter(|s| s.verdict.is_attempt())
+    }
+
+    /// Why `answer` shouldn't be sent, if earlier attempts already tell us
+    /// it's wrong or the part is solved
+    pub fn reject(&self, year: u32, day: u32, part: u32, answer: &str) -> Option<String> {
+        let value = answer.parse::<i64>().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            let bound = attempt.answer.parse::<i64>().ok();
+
+            match (attempt.verdict, value, bound) {
+                (Verdict::Correct, ..) => {
+                    return Some(format!("already solved with {}", attempt.answer))
+                }
+                _ if attempt.answer == answer => {
+                    return Some(format!(
+                        "{answer} was already rejected ({})",
+                        attempt.verdict
+                    ))
+                }
+                (Verdict::TooHigh, Some(v), Some(b)) if v >= b => {
+                    return Some(format!("{answer} is not below {b}, which was too high"))
+                }
+                (Verdict::TooLow, Some(v), Some(b)) if v <= b => {
+                    return Some(format!("{answer} is not above {b}, which was too low"))
+                }
+                _ => (),
+            }
+        }
+
+        None
+    }
+
+    pub fn record(&mut self, submission: Submission) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{timestamp}\t{}\t{}\t{}\t{}\t{}",
+            submission.year, submission.day, submission.part, submission.answer, submission.verdict
+        )?;
+
+        self.entries.push(submission);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn rejects_known_answers() {
+        let path = env::temp_dir().join(format!("aoc-submissions-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut log = SubmissionLog::load(&path).unwrap();
+        for (answer, verdict) in [
+            ("100", Verdict::TooHigh),
+            ("10", Verdict::TooLow),
+            ("50", Verdict::Wait(Duration::from_secs(30))),
+            ("abc", Verdict::Wrong),
+        ] {
+            log.record(Submission {
+                year: 2024,
+                day: 1,
+                part: 1,
+                answer: answer.to_string(),
+                verdict,
+            })
+            .unwrap();
+        }
+
+        let log = SubmissionLog::load(&path).unwrap();
+        assert!(log.reject(2024, 1, 1, "100").is_some());
+        assert!(log.reject(2024, 1, 1, "150").is_some());
+        assert!(log.reject(2024, 1, 1, "5").is_some());
+        assert!(log.reject(2024, 1, 1, "abc").is_some());
+        assert_eq!(log.reject(2024, 1, 1, "50"), None);
+        assert_eq!(log.reject(2024, 1, 2, "100"), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,40 @@
+//! Solutions for testing the runner and the tools built on it
+
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved, Variant},
+};
+
+/// One number per line. Part 1 sums them, and so does its `unlucky` variant,
+/// except that it skips 13s. Part 2 is the first number, so it panics on an
+/// empty input.
+pub struct Numbers;
+
+impl Solution for Numbers {
+    const YEAR: u32 = 0;
+    const DAY: u32 = 0;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Span::new(0, input)
+            .lines()
+            .map(|line| line.parse())
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<i32>) -> Solved {
+        Ok(numbers.iter().sum::<i32>().into())
+    }
+
+    fn part2(numbers: &Vec<i32>) -> Solved {
+        Ok(numbers[0].into())
+    }
+
+    fn variants() -> Vec<Variant<Vec<i32>>> {
+        vec![Variant::new(1, "unlucky", |numbers| {
+            Ok(numbers.iter().filter(|&&n| n != 13).sum::<i32>().into())
+        })]
+    }
+}
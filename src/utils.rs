@@ -7,6 +7,8 @@ use std::{
     ops::{Add, Mul, Sub},
 };
 
+use crate::error::Result;
+
 pub type Grid<T> = Vec<Vec<T>>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -135,6 +137,22 @@ pub fn read_grid<T>(input: &str, mut parse: impl FnMut(Point, char) -> T) -> Gri
         .collect()
 }
 
+pub fn try_read_grid<T>(
+    input: &str,
+    mut parse: impl FnMut(Point, char) -> Result<T>,
+) -> Result<Grid<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| parse(Point(x as _, y as _), c))
+                .collect()
+        })
+        .collect()
+}
+
 /// (nrows, ncols)
 pub fn shape<T>(grid: &Grid<T>) -> (usize, usize) {
     (grid.len(), grid[0].len())
@@ -151,14 +169,20 @@ pub fn print_grid(grid: &Grid<impl Display>) {
     println!();
 }
 
+/// The grid as lines of text, the inverse of `read_grid` with `|_, c| c`
+pub fn grid_to_string(grid: &Grid<char>) -> String {
+    grid.iter()
+        .map(|line| line.iter().collect::<String>() + "\n")
+        .collect()
+}
+
 pub fn get<T: Clone>(grid: &Grid<T>, pos: Point) -> Option<T> {
     if pos.0 < 0 || pos.1 < 0 {
         return None;
     }
 
     grid.get(pos.1 as usize)
-        .map(|row| row.get(pos.0 as usize).cloned())
-        .flatten()
+        .and_then(|row| row.get(pos.0 as usize).cloned())
 }
 
 pub fn get_mut<T: Clone>(grid: &mut Grid<T>, pos: Point) -> Option<&mut T> {
@@ -167,8 +191,7 @@ pub fn get_mut<T: Clone>(grid: &mut Grid<T>, pos: Point) -> Option<&mut T> {
     }
 
     grid.get_mut(pos.1 as usize)
-        .map(|row| row.get_mut(pos.0 as usize))
-        .flatten()
+        .and_then(|row| row.get_mut(pos.0 as usize))
 }
 
 pub fn set<T: Clone>(grid: &mut Grid<T>, pos: Point, val: T) {
@@ -181,32 +204,24 @@ pub fn set<T: Clone>(grid: &mut Grid<T>, pos: Point, val: T) {
 }
 
 pub fn enumerate<T>(grid: &Grid<T>) -> impl Iterator<Item = (Point, &T)> {
-    grid.iter()
-        .enumerate()
-        .map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .map(move |(x, val)| (Point(x as i32, y as i32), val))
-        })
-        .flatten()
+    grid.iter().enumerate().flat_map(|(y, row)| {
+        row.iter()
+            .enumerate()
+            .map(move |(x, val)| (Point(x as i32, y as i32), val))
+    })
 }
 
 pub fn enumerate_mut<T>(grid: &mut Grid<T>) -> impl Iterator<Item = (Point, &mut T)> {
-    grid.iter_mut()
-        .enumerate()
-        .map(|(y, row)| {
-            row.iter_mut()
-                .enumerate()
-                .map(move |(x, val)| (Point(x as i32, y as i32), val))
-        })
-        .flatten()
+    grid.iter_mut().enumerate().flat_map(|(y, row)| {
+        row.iter_mut()
+            .enumerate()
+            .map(move |(x, val)| (Point(x as i32, y as i32), val))
+    })
 }
 
 /// shape must be (nrows, ncols)
 pub fn enumerate_pos(shape: (usize, usize)) -> impl Iterator<Item = Point> {
-    (0..shape.0)
-        .map(move |y| (0..shape.1).map(move |x| Point(x as i32, y as i32)))
-        .flatten()
+    (0..shape.0).flat_map(move |y| (0..shape.1).map(move |x| Point(x as i32, y as i32)))
 }
 
 #[derive(PartialEq, Eq)]
@@ -220,7 +235,7 @@ where
     T: Eq,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.cost.partial_cmp(&self.cost)
+        Some(self.cmp(other))
     }
 }
 
@@ -276,7 +291,7 @@ where
 
 pub fn pop_min<T>(v: &mut Vec<T>, selector: impl Fn(&T) -> i32) -> Option<T> {
     let mut i = None;
-    let mut lowest = i32::max_value();
+    let mut lowest = i32::MAX;
 
     for (idx, val) in v.iter().enumerate() {
         let cost = selector(val);
@@ -0,0 +1,149 @@
+//! Checks for the structure of a puzzle input, for `Solution::validate`. Each
+//! returns every problem it finds, not only the first.
+
+use crate::error::ParseError;
+
+/// Every char of `text` with its 1-based line and column, where `text` starts
+/// at `line`
+fn chars(text: &str, line: usize) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+    text.lines().enumerate().flat_map(move |(i, l)| {
+        l.chars()
+            .enumerate()
+            .map(move |(j, c)| (line + i, j + 1, c))
+    })
+}
+
+/// `what` appears exactly once in `text`, e.g. the start of a map
+pub fn exactly_one(day: u32, text: &str, line: usize, what: char) -> Vec<ParseError> {
+    let mut found = chars(text, line).filter(|&(.., c)| c == what);
+
+    let Some((first_line, first_column, _)) = found.next() else {
+        return vec![ParseError::new(
+            day,
+            line,
+            1,
+            format!("a `{what}` somewhere"),
+        )];
+    };
+    found
+        .map(|(l, c, _)| {
+            ParseError::new(
+                day,
+                l,
+                c,
+                format!(
+                    "only one `{what}`, the first is at line {first_line}, column {first_column}"
+                ),
+            )
+            .found(what.to_string())
+        })
+        .collect()
+}
+
+/// All lines of `text` are as long as the first, and there is at least one
+pub fn rectangular(day: u32, text: &str, line: usize) -> Vec<ParseError> {
+    let Some(width) = text.lines().next().map(|l| l.chars().count()) else {
+        return vec![ParseError::new(day, line, 1, "a map").found("")];
+    };
+
+    text.lines()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let len = l.chars().count();
+            (len != width).then(|| {
+                ParseError::new(
+                    day,
+                    line + i,
+                    len.min(width) + 1,
+                    format!("lines of {width} tiles like the first"),
+                )
+                .found(format!("{len} tiles"))
+            })
+        })
+        .collect()
+}
+
+/// Every tile on the border of the map in `text` is `wall`
+pub fn walled(day: u32, text: &str, line: usize, wall: char) -> Vec<ParseError> {
+    let last = text.lines().count().saturating_sub(1);
+
+    text.lines()
+        .enumerate()
+        .flat_map(|(i, l)| {
+            let width = l.chars().count();
+            l.chars().enumerate().filter_map(move |(j, tile)| {
+                let border = i == 0 || i == last || j == 0 || j + 1 == width;
+                (border && tile != wall).then(|| {
+                    ParseError::new(day, line + i, j + 1, format!("a `{wall}` on the border"))
+                        .found(tile.to_string())
+                })
+            })
+        })
+        .collect()
+}
+
+/// Every char of `text` is one of `allowed`
+pub fn only(
+    day: u32,
+    text: &str,
+    line: usize,
+    allowed: impl Fn(char) -> bool,
+    what: &str,
+) -> Vec<ParseError> {
+    chars(text, line)
+        .filter(|&(.., c)| !allowed(c))
+        .map(|(l, c, found)| {
+            ParseError::new(day, l, c, what).found(found.escape_debug().to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_every_problem() {
+        let map = "#####\n#.^.#\n#.#^.\n#^###\n";
+
+        let errors = exactly_one(6, map, 1, '^');
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            [(3, 4), (4, 2)]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "day 6, line 3, column 4: expected only one `^`, the first is at line 2, column 3, found `^`"
+        );
+        assert_eq!(exactly_one(6, "...", 3, '^')[0].line, 3);
+
+        let errors = walled(15, map, 3, '#');
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            [(5, 5), (6, 2)]
+        );
+
+        let errors = rectangular(4, "XMAS\nXM\nXMASX\n", 1);
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            [(2, 3), (3, 5)]
+        );
+        assert!(rectangular(4, map, 1).is_empty());
+        assert_eq!(rectangular(4, "", 1).len(), 1);
+
+        let errors = only(9, "12a4\n5", 1, |c| c.is_ascii_digit(), "a digit");
+        assert_eq!(
+            errors[0].to_string(),
+            "day 9, line 1, column 3: expected a digit, found `a`"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::bench;
+
+/// The files that `watch` polls for a day
+pub fn watched_files(input_dir: impl AsRef<Path>, year: u32, day: u32) -> [PathBuf; 2] {
+    [
+        PathBuf::from(format!("src/year{year}/day{day}.rs")),
+        crate::client::input_path(input_dir, year, day),
+    ]
+}
+
+/// The latest modification time of `paths`, ignoring the ones that don't exist
+pub fn last_modified(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+/// The part of a `run --format json` record that `diff` compares
+#[derive(Deserialize)]
+struct Record {
+    part: u32,
+    variant: Option<String>,
+    answer: Option<Value>,
+    solve_ns: Option<u64>,
+    error: Option<String>,
+}
+
+/// One result of a day's run, either the answer and solve time or the error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved { answer: String, solve: Duration },
+    Failed(String),
+}
+
+/// The outcomes of one run of a day, keyed by part and variant
+#[derive(Debug, Default)]
+pub struct Snapshot(BTreeMap<(u32, Option<String>), Outcome>);
+
+impl Snapshot {
+    /// Reads the output of `run --format json`
+    pub fn from_json_lines(s: &str) -> serde_json::Result<Self> {
+        let mut outcomes = BTreeMap::new();
+        for line in s.lines() {
+            let record = serde_json::from_str::<Record>(line)?;
+            let outcome = match (record.answer, record.error) {
+                (Some(answer), _) => Outcome::Solved {
+                    answer: match answer {
+                        Value::String(s) => s,
+                        answer => answer.to_string(),
+                    },
+                    solve: Duration::from_nanos(record.solve_ns.unwrap_or(0)),
+                },
+                (None, error) => Outcome::Failed(error.unwrap_or_default()),
+            };
+            outcomes.insert((record.part, record.variant), outcome);
+        }
+
+        Ok(Snapshot(outcomes))
+    }
+}
+
+/// One line per part and variant of `next`, with the changes since `previous`
+pub fn diff(previous: &Snapshot, next: &Snapshot) -> String {
+    let mut lines = String::new();
+
+    for ((part, variant), outcome) in &next.0 {
+        let mut line = format!("Part {part}");
+        if let Some(variant) = variant {
+            line += &format!(" - {variant}");
+        }
+
+        line += &match (previous.0.get(&(*part, variant.clone())), outcome) {
+            (_, Outcome::Failed(e)) => format!(": failed: {e}"),
+            (
+                Some(Outcome::Solved {
+                    answer: old,
+                    solve: old_solve,
+                }),
+                Outcome::Solved { answer, solve },
+            ) => {
+                let answer = if answer == old {
+                    answer.clone()
+                } else {
+                    format!("{old} -> {answer} (changed)")
+                };
+                let change = bench::change(*old_solve, *solve);
+                format!(": {answer}, {old_solve:.2?} -> {solve:.2?} ({change})")
+            }
+            (_, Outcome::Solved { answer, solve }) => format!(": {answer}, {solve:.2?}"),
+        };
+
+        lines += &line;
+        lines += "\n";
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_runs() {
+        let previous = Snapshot::from_json_lines(
+            r#"{"year":2024,"day":2,"part":1,"variant":null,"answer":571,"answer_type":"int","parse_ns":1500,"solve_ns":2000,"error":null}
+{"year":2024,"day":2,"part":2,"variant":null,"answer":612,"answer_type":"int","parse_ns":1500,"solve_ns":1000,"error":null}
+"#,
+        )
+        .unwrap();
+        let next = Snapshot::from_json_lines(
+            r#"{"year":2024,"day":2,"part":1,"variant":null,"answer":572,"answer_type":"int","parse_ns":1500,"solve_ns":1000,"error":null}
+{"year":2024,"day":2,"part":1,"variant":"naive","answer":"572","answer_type":"string","parse_ns":1500,"solve_ns":3000,"error":null}
+{"year":2024,"day":2,"part":2,"variant":null,"answer":612,"answer_type":"int","parse_ns":1500,"solve_ns":1500,"error":null}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            diff(&previous, &next),
+            "\
+Part 1: 571 -> 572 (changed), 2.00µs -> 1.00µs (-50.0%)
+Part 1 - naive: 572, 3.00µs
+Part 2: 612, 1.00µs -> 1.50µs (+50.0%)
+"
+        );
+
+        let failed = Snapshot::from_json_lines(
+            r#"{"year":2024,"day":2,"part":1,"variant":null,"answer":null,"answer_type":null,"parse_ns":null,"solve_ns":null,"error":"panicked: oops"}"#,
+        )
+        .unwrap();
+        assert_eq!(diff(&next, &failed), "Part 1: failed: panicked: oops\n");
+
+        let instant = Snapshot::from_json_lines(
+            r#"{"year":2024,"day":2,"part":2,"variant":null,"answer":612,"answer_type":"int","parse_ns":1500,"solve_ns":0,"error":null}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            diff(&instant, &next),
+            "\
+Part 1: 572, 1.00µs
+Part 1 - naive: 572, 3.00µs
+Part 2: 612, 0.00ns -> 1.50µs (n/a)
+"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::runner::{entries, entries_with_params, Entry};
+
+pub const YEAR: u32 = 2024;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Every solution of the year, in order of day
+pub fn solutions() -> Vec<Entry> {
+    [
+        entries::<day1::Day1>(),
+        entries::<day2::Day2>(),
+        entries::<day3::Day3>(),
+        entries::<day4::Day4>(),
+        entries::<day5::Day5>(),
+        entries::<day6::Day6>(),
+        entries::<day7::Day7>(),
+        entries::<day8::Day8>(),
+        entries::<day9::Day9>(),
+        entries::<day10::Day10>(),
+        entries_with_params::<day11::Day11>(),
+        entries::<day12::Day12>(),
+        entries::<day13::Day13>(),
+        entries_with_params::<day14::Day14>(),
+        entries::<day15::Day15>(),
+        entries::<day16::Day16>(),
+        entries::<day17::Day17>(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+#[cfg(feature = "nightly")]
+use std::simd::{i32x8, num::SimdInt};
+
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved},
+};
+
+const DAY: u32 = 1;
+
+/// Both location lists, of the same length
+pub struct Lists {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+fn parse(input: &str) -> Result<Lists> {
+    let mut lists = Lists {
+        left: vec![],
+        right: vec![],
+    };
+
+    for line in Span::new(DAY, input).lines() {
+        let (a, b) = line.split_once(" ")?;
+        lists.left.push(a.parse()?);
+        lists.right.push(b.trim().parse()?);
+    }
+
+    Ok(lists)
+}
+
+fn sorted(lists: &Lists) -> (Vec<i32>, Vec<i32>) {
+    let mut v1 = lists.left.clone();
+    let mut v2 = lists.right.clone();
+
+    v1.sort_unstable();
+    v2.sort_unstable();
+
+    (v1, v2)
+}
+
+#[cfg(feature = "nightly")]
+fn part1(lists: &Lists) -> i32 {
+    let (v1, v2) = sorted(lists);
+
+    let chunk_size = 8;
+    let len = v1.len();
+
+    let mut i = 0;
+    let mut sum = 0;
+
+    unsafe {
+        while i + chunk_size <= len {
+            let a = i32x8::from_slice(v1.get_unchecked(i..i + chunk_size));
+            let b = i32x8::from_slice(v2.get_unchecked(i..i + chunk_size));
+            sum += (a - b).abs().reduce_sum();
+            i += chunk_size;
+        }
+
+        while i < len {
+            sum += (v1.get_unchecked(i) - v2.get_unchecked(i)).abs();
+            i += 1;
+        }
+    }
+
+    sum
+}
+
+#[cfg(not(feature = "nightly"))]
+fn part1(lists: &Lists) -> i32 {
+    let (v1, v2) = sorted(lists);
+
+    v1.iter().zip(&v2).map(|(a, b)| (a - b).abs()).sum()
+}
+
+fn part2(lists: &Lists) -> i32 {
+    let mut count = HashMap::with_capacity(lists.right.len());
+    for &b in &lists.right {
+        count
+            .entry(b)
+            .and_modify(|counter| *counter += 1)
+            .or_insert(1);
+    }
+
+    lists
+        .left
+        .iter()
+        .map(|&x| x * count.get(&x).unwrap_or(&0))
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST).unwrap()), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST).unwrap()), 31);
+    }
+}
@@ -0,0 +1,128 @@
+use rayon::prelude::*;
+
+use crate::{
+    error::{ParseError, Result},
+    solution::{Solution, Solved, Variant},
+    utils::{enumerate, get, set, try_read_grid, Grid, Point},
+};
+
+const DAY: u32 = 10;
+
+fn parse(input: &str) -> Result<Grid<i32>> {
+    try_read_grid(input, |pos, c| {
+        c.to_digit(10).map(|d| d as i32).ok_or_else(|| {
+            ParseError::new(DAY, pos.1 as usize + 1, pos.0 as usize + 1, "a digit")
+                .found(c.to_string())
+        })
+    })
+}
+
+fn search(pos: Point, grid: &mut Grid<i32>, is_part_one: bool) -> i32 {
+    let val = get(grid, pos).unwrap();
+
+    if val == 9 {
+        if is_part_one {
+            set(grid, pos, -1);
+        }
+        return 1;
+    }
+
+    let mut count = 0;
+    for dir in Point::orth_dirs() {
+        let new_pos = pos + dir;
+        if let Some(next) = get(grid, new_pos) {
+            if next == val + 1 {
+                count += search(new_pos, grid, is_part_one);
+            }
+        }
+    }
+
+    count
+}
+
+fn part1(grid: &Grid<i32>) -> i32 {
+    let mut count = 0;
+    for (pos, &val) in enumerate(grid) {
+        if val == 0 {
+            count += search(pos, &mut grid.clone(), true);
+        }
+    }
+
+    count
+}
+
+fn part2(grid: &Grid<i32>) -> i32 {
+    let mut count = 0;
+    for (pos, &val) in enumerate(grid) {
+        if val == 0 {
+            count += search(pos, &mut grid.clone(), false);
+        }
+    }
+
+    count
+}
+
+fn parallel(grid: &Grid<i32>, is_part_one: bool) -> i32 {
+    let trailheads = enumerate(grid)
+        .filter(|&(_, &val)| val == 0)
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+
+    trailheads
+        .par_iter()
+        .map(|&pos| search(pos, &mut grid.clone(), is_part_one))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, "parallel", |grid| Ok(parallel(grid, true).into())),
+            Variant::new(2, "parallel", |grid| Ok(parallel(grid, false).into())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST).unwrap()), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST).unwrap()), 81);
+        assert_eq!(parallel(&parse(TEST).unwrap(), false), 81);
+    }
+}
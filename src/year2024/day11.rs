@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{
+    error::{NoAnswer, Result},
+    parse::Span,
+    solution::{Solution, Solved, WithParams},
+};
+
+const DAY: u32 = 11;
+
+/// Stones that `val` turns into after `n` blinks, `None` if that doesn't fit
+/// in a `u64`. Values are `u128`, since multiplying a `u64` stone by 2024
+/// can overflow, and a stone with an odd number of digits is split on the
+/// blink after that.
+fn count(n: usize, val: u128, cache: &mut HashMap<(usize, u128), Option<u64>>) -> Option<u64> {
+    if n == 0 {
+        return Some(1);
+    }
+
+    if let Some(c) = cache.get(&(n, val)) {
+        return *c;
+    }
+
+    let c = if val == 0 {
+        count(n - 1, 1, cache)
+    } else {
+        let num_digits = val.ilog10() + 1;
+        if num_digits.is_multiple_of(2) {
+            let p = 10u128.pow(num_digits / 2);
+            let a = val / p;
+            let b = val % p;
+
+            count(n - 1, a, cache).and_then(|a| a.checked_add(count(n - 1, b, cache)?))
+        } else {
+            count(n - 1, val * 2024, cache)
+        }
+    };
+
+    cache.insert((n, val), c);
+
+    c
+}
+
+/// How many times each part blinks
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Blinks {
+    pub part1: usize,
+    pub part2: usize,
+}
+
+impl Default for Blinks {
+    fn default() -> Self {
+        Blinks {
+            part1: 25,
+            part2: 75,
+        }
+    }
+}
+
+pub struct Stones {
+    stones: Vec<u64>,
+    blinks: Blinks,
+}
+
+fn parse(input: &str, blinks: Blinks) -> Result<Stones> {
+    Ok(Stones {
+        stones: Span::new(DAY, input)
+            .split_whitespace()
+            .map(|x| x.parse())
+            .collect::<Result<_>>()?,
+        blinks,
+    })
+}
+
+/// `None` if there are more stones than fit in a `u64`
+fn blink(stones: &[u64], n: usize) -> Option<u64> {
+    let mut cache = HashMap::new();
+    stones.iter().try_fold(0u64, |total, &x| {
+        total.checked_add(count(n, x.into(), &mut cache)?)
+    })
+}
+
+fn part1(input: &Stones) -> Option<u64> {
+    blink(&input.stones, input.blinks.part1)
+}
+
+fn part2(input: &Stones) -> Option<u64> {
+    blink(&input.stones, input.blinks.part2)
+}
+
+fn too_many() -> NoAnswer {
+    NoAnswer::new(DAY, "there are more stones than fit in 64 bits")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Stones;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input, Blinks::default())
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).ok_or_else(too_many)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).ok_or_else(too_many)?.into())
+    }
+}
+
+impl WithParams for Day11 {
+    type Params = Blinks;
+
+    fn parse_with(input: &str, blinks: &Blinks) -> Result<Self::Input> {
+        parse(input, *blinks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "125 17";
+
+    #[test]
+    fn part1_example() {
+        let blinks = Blinks {
+            part1: 6,
+            part2: 25,
+        };
+        assert_eq!(part1(&parse(TEST, blinks).unwrap()), Some(22));
+        assert_eq!(part2(&parse(TEST, blinks).unwrap()), Some(55312));
+    }
+
+    #[test]
+    fn large_stones() {
+        let blinks = Blinks {
+            part1: 1,
+            part2: 1000,
+        };
+        let stones = parse("9999999999999999999", blinks).unwrap();
+        assert_eq!(part1(&stones), Some(1));
+        assert_eq!(
+            Day11::part2(&stones).unwrap_err().to_string(),
+            "day 11: no answer, there are more stones than fit in 64 bits"
+        );
+
+        assert_eq!(
+            parse("125 -5", blinks).err().unwrap().to_string(),
+            "day 11, line 1, column 5: expected a value of type `u64`, found `-5`"
+        );
+    }
+}
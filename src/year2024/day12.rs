@@ -1,6 +1,12 @@
-use aoc_runner_derive::aoc;
-
-use crate::utils::{enumerate_pos, get, read_grid, set, shape, Grid, Point};
+use crate::{
+    error::Result,
+    solution::{Solution, Solved},
+    utils::{enumerate_pos, get, read_grid, set, shape, Grid, Point},
+};
+
+fn parse(input: &str) -> Result<Grid<char>> {
+    Ok(read_grid(input, |_, c| c))
+}
 
 fn find_region(pos: Point, grid: &mut Grid<char>) -> (i32, i32, i32) {
     let species = get(grid, pos).unwrap();
@@ -44,8 +50,8 @@ fn find_region(pos: Point, grid: &mut Grid<char>) -> (i32, i32, i32) {
             let neighbour = get(grid, new_pos);
 
             if neighbour.is_some_and(|s| s.to_ascii_lowercase() != placeholder) {
-                let a = get(&grid, new_pos + Point(0, -dir.1));
-                let b = get(&grid, new_pos + Point(-dir.0, 0));
+                let a = get(grid, new_pos + Point(0, -dir.1));
+                let b = get(grid, new_pos + Point(-dir.0, 0));
                 num_sides += (a.is_some_and(|s| s == placeholder)
                     && b.is_some_and(|s| s == placeholder)) as i32;
             }
@@ -62,9 +68,8 @@ fn find_region(pos: Point, grid: &mut Grid<char>) -> (i32, i32, i32) {
     (area, perimeter, num_sides)
 }
 
-#[aoc(day12, part1)]
-fn part1(input: &str) -> i32 {
-    let mut grid = read_grid(input, |_, c| c);
+fn part1(grid: &Grid<char>) -> i32 {
+    let mut grid = grid.clone();
     let mut count = 0;
     for pos in enumerate_pos(shape(&grid)) {
         let (area, perimeter, _) = find_region(pos, &mut grid);
@@ -74,9 +79,8 @@ fn part1(input: &str) -> i32 {
     count
 }
 
-#[aoc(day12, part2)]
-fn part2(input: &str) -> i32 {
-    let mut grid = read_grid(input, |_, c| c);
+fn part2(grid: &Grid<char>) -> i32 {
+    let mut grid = grid.clone();
     let mut count = 0;
     for pos in enumerate_pos(shape(&grid)) {
         let (area, _, num_sides) = find_region(pos, &mut grid);
@@ -86,6 +90,27 @@ fn part2(input: &str) -> i32 {
     count
 }
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,11 +128,11 @@ MMMISSJEEE";
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(TEST), 1930);
+        assert_eq!(part1(&parse(TEST).unwrap()), 1930);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(TEST), 1206);
+        assert_eq!(part2(&parse(TEST).unwrap()), 1206);
     }
 }
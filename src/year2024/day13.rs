@@ -0,0 +1,156 @@
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved},
+};
+
+const DAY: u32 = 13;
+
+// X = alpha * A1 + beta * B1
+// Y = alpha * A2 + beta * B2
+// => (alpha) = M^-1 . (X)
+//    (beta )          (Y)
+//
+//    M^-1 = 1 / det(M) . (B2  -B1)
+//                        (-A2  A1)
+// => alpha = (B2*X - B1*Y)/det(M)
+// => beta = (-A2*X + A1*Y)/det(M)
+
+/// `X+1, Y+2` or `X=1, Y=2`, depending on `sign`
+fn extract(s: Span, sign: &str) -> Result<(i64, i64)> {
+    let (x, y) = s.split_once(", ")?;
+    Ok((
+        x.strip_prefix("X")?.strip_prefix(sign)?.parse()?,
+        y.strip_prefix("Y")?.strip_prefix(sign)?.parse()?,
+    ))
+}
+
+/// Button A, button B and the prize
+pub type Machine = [(i64, i64); 3];
+
+/// Groups of three lines, `parse` makes sure the last group is complete
+#[cfg(feature = "nightly")]
+fn groups<'a, 'b>(lines: &'b [Span<'a>]) -> impl Iterator<Item = [Span<'a>; 3]> + 'b {
+    lines.iter().copied().array_chunks()
+}
+
+#[cfg(not(feature = "nightly"))]
+fn groups<'a, 'b>(lines: &'b [Span<'a>]) -> impl Iterator<Item = [Span<'a>; 3]> + 'b {
+    lines
+        .chunks_exact(3)
+        .map(|group| [group[0], group[1], group[2]])
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>> {
+    let input = Span::new(DAY, input);
+    let lines = input
+        .lines()
+        .filter(|line| !line.as_str().trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if lines.len() % 3 != 0 {
+        let missing = ["`Button A: `", "`Button B: `", "`Prize: `"][lines.len() % 3];
+        let end = input.skip(input.as_str().len());
+        return Err(end.error(format!("{missing} to complete the last machine")));
+    }
+
+    groups(&lines)
+        .map(|[a, b, prize]| {
+            Ok([
+                extract(a.strip_prefix("Button A: ")?, "+")?,
+                extract(b.strip_prefix("Button B: ")?, "+")?,
+                extract(prize.strip_prefix("Prize: ")?, "=")?,
+            ])
+        })
+        .collect()
+}
+
+fn solve(machines: &[Machine], is_part_one: bool) -> i64 {
+    machines
+        .iter()
+        .map(|&[(a1, a2), (b1, b2), (mut x, mut y)]| {
+            if !is_part_one {
+                x += 10000000000000;
+                y += 10000000000000;
+            }
+
+            let det = (a1 * b2 - a2 * b1) as f64;
+            if det == 0. {
+                return 0;
+            }
+
+            let alpha = (b2 * x - b1 * y) as f64 / det;
+            let beta = (-a2 * x + a1 * y) as f64 / det;
+
+            if (beta - beta.round()).abs() + (alpha - alpha.round()).abs() > 1.0e-9 {
+                return 0;
+            }
+
+            (alpha * 3. + beta) as i64
+        })
+        .sum()
+}
+
+fn part1(machines: &[Machine]) -> i64 {
+    solve(machines, true)
+}
+
+fn part2(machines: &[Machine]) -> i64 {
+    solve(machines, false)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST).unwrap()), 480);
+    }
+
+    #[test]
+    fn incomplete_machine() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\n";
+        assert_eq!(
+            parse(input).unwrap_err().to_string(),
+            "day 13, line 3, column 1: expected `Prize: ` to complete the last machine, found nothing"
+        );
+    }
+}
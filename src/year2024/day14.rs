@@ -0,0 +1,239 @@
+use fastrand::Rng;
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved, Variant, WithParams},
+    utils::Point,
+};
+
+const DAY: u32 = 14;
+
+fn parse_robot(line: Span) -> Result<(Point, Point)> {
+    let (p_parts, v_parts) = line.split_once(" ")?;
+    let (px, py) = p_parts.strip_prefix("p=")?.split_once(",")?;
+    let (vx, vy) = v_parts.strip_prefix("v=")?.split_once(",")?;
+
+    let p = Point(px.parse()?, py.parse()?);
+    let v = Point(vx.parse()?, vy.parse()?);
+    Ok((p, v))
+}
+
+/// The area the robots move in, which is smaller for the example
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Area {
+    pub rows: i32,
+    pub cols: i32,
+    /// How long part 2 looks for the tree
+    pub max_seconds: i32,
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        Area {
+            rows: 103,
+            cols: 101,
+            max_seconds: 100_000,
+        }
+    }
+}
+
+pub struct Input {
+    robots: Vec<(Point, Point)>,
+    area: Area,
+}
+
+fn parse(input: &str, area: Area) -> Result<Input> {
+    Ok(Input {
+        robots: Span::new(DAY, input)
+            .lines()
+            .map(parse_robot)
+            .collect::<Result<_>>()?,
+        area,
+    })
+}
+
+fn sim(n: i32, p: Point, v: Point, nrows: i32, ncols: i32) -> Point {
+    let res = p + n * v;
+
+    Point(res.0.rem_euclid(ncols), res.1.rem_euclid(nrows))
+}
+
+fn part1(input: &Input) -> i32 {
+    let (nrows, ncols) = (input.area.rows, input.area.cols);
+
+    let mut counts = [0; 4];
+
+    for &(p, v) in &input.robots {
+        let new_pos = sim(100, p, v, nrows, ncols);
+
+        let (middle_row, middle_col) = (nrows / 2, ncols / 2);
+        if new_pos.0 == middle_col || new_pos.1 == middle_row {
+            continue;
+        }
+
+        let is_top = new_pos.1 < middle_row;
+        let is_left = new_pos.0 < middle_col;
+        counts[is_top as usize + (is_left as usize) * 2] += 1;
+    }
+
+    counts.iter().product()
+}
+
+fn density(positions: &Vec<Point>) -> f32 {
+    let mut dist = 0;
+    for pos1 in positions {
+        for pos2 in positions {
+            dist += pos1.dist_squared(*pos2);
+        }
+    }
+
+    1. / dist as f32
+}
+
+fn part2(input: &Input) -> i32 {
+    let (robots, nrows, ncols) = (&input.robots, input.area.rows, input.area.cols);
+
+    let mut positions = vec![];
+    let mut seconds = 1;
+    let mut highest_density = 0.;
+    let mut best = -1;
+
+    loop {
+        positions.clear();
+
+        for (p, v) in robots {
+            let new_pos = sim(seconds, *p, *v, nrows, ncols);
+            positions.push(new_pos);
+        }
+        let current_density = density(&positions);
+        if current_density > highest_density {
+            highest_density = current_density;
+            best = seconds;
+        }
+
+        seconds += 1;
+        if seconds > input.area.max_seconds {
+            return best;
+        }
+    }
+}
+
+/// Like `part2`, but scores the seconds in parallel. Ties go to the earliest
+/// second, as in `part2`.
+fn part2_parallel(input: &Input) -> i32 {
+    let (robots, nrows, ncols) = (&input.robots, input.area.rows, input.area.cols);
+
+    let (_, best) = (1..=input.area.max_seconds.max(1))
+        .into_par_iter()
+        .map(|seconds| {
+            let positions = robots
+                .iter()
+                .map(|(p, v)| sim(seconds, *p, *v, nrows, ncols))
+                .collect();
+            (density(&positions), seconds)
+        })
+        .reduce(
+            || (0., -1),
+            |a, b| {
+                if b.0 > a.0 || (b.0 == a.0 && b.1 < a.1) {
+                    b
+                } else {
+                    a
+                }
+            },
+        );
+
+    best
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input, Area::default())
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant::new(2, "parallel", |input| {
+            Ok(part2_parallel(input).into())
+        })]
+    }
+
+    /// `size` robots anywhere in the default 101 × 103 area, moving up to 100
+    /// tiles per second in either direction
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let robots = (0..size).map(|_| {
+            format!(
+                "p={},{} v={},{}\n",
+                rng.i32(0..101),
+                rng.i32(0..103),
+                rng.i32(-100..=100),
+                rng.i32(-100..=100)
+            )
+        });
+
+        Some(robots.collect())
+    }
+}
+
+impl WithParams for Day14 {
+    type Params = Area;
+
+    fn validate_params(area: &Area) -> std::result::Result<(), String> {
+        for (name, size) in [("rows", area.rows), ("cols", area.cols)] {
+            if size <= 0 {
+                return Err(format!("`{name}` must be positive, not {size}"));
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_with(input: &str, area: &Area) -> Result<Self::Input> {
+        parse(input, *area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn part1_example() {
+        let area = Area {
+            rows: 7,
+            cols: 11,
+            ..Area::default()
+        };
+        assert_eq!(part1(&parse(TEST, area).unwrap()), 12);
+    }
+}
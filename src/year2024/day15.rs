@@ -0,0 +1,259 @@
+use fastrand::Rng;
+
+use crate::{
+    error::{ParseError, Result},
+    parse::Span,
+    solution::{Solution, Solved},
+    utils::{enumerate, get, grid_to_string, set, try_read_grid, Grid, Point},
+    validate,
+};
+
+const DAY: u32 = 15;
+
+const TILES: &str = "a tile, one of `#.O@`";
+const MOVES: &str = "a move, one of `<>^v`";
+
+fn parse(input: &str) -> Result<(Point, Grid<char>, Vec<Point>)> {
+    let (grid_parts, move_parts) = Span::new(DAY, input).split_once("\n\n")?;
+    let mut start_pos = Point::zero();
+    let grid = try_read_grid(grid_parts.as_str(), |pos, c| match c {
+        '#' | '.' | 'O' => Ok(c),
+        '@' => {
+            start_pos = pos;
+            Ok(c)
+        }
+        _ => Err(
+            ParseError::new(DAY, pos.1 as usize + 1, pos.0 as usize + 1, TILES)
+                .found(c.to_string()),
+        ),
+    })?;
+    let moves_start = input.len() - move_parts.as_str().len();
+    let moves = move_parts
+        .as_str()
+        .char_indices()
+        .filter(|&(_, c)| c != '\n')
+        .map(|(i, c)| match c {
+            '^' => Ok(Point::NORTH),
+            '>' => Ok(Point::EAST),
+            'v' => Ok(Point::SOUTH),
+            '<' => Ok(Point::WEST),
+            _ => Err(ParseError::at(DAY, input, moves_start + i, MOVES)),
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((start_pos, grid, moves))
+}
+
+fn do_move(grid: &mut Grid<char>, pos: Point, dir: Point) -> bool {
+    let new_pos = pos + dir;
+    let cur_val = get(grid, pos).unwrap();
+    let next_val = get(grid, new_pos).unwrap();
+
+    if next_val == '#' {
+        return false;
+    }
+
+    let success = if next_val == 'O' {
+        // part one
+        do_move(grid, new_pos, dir)
+    } else if next_val == '[' || next_val == ']' {
+        // part two
+        // vertical moves are tricky
+        if dir.0 == 0 {
+            let neighbour_pos = if next_val == '[' {
+                new_pos + Point::EAST
+            } else {
+                new_pos + Point::WEST
+            };
+            do_move(grid, new_pos, dir) && do_move(grid, neighbour_pos, dir)
+        } else {
+            do_move(grid, new_pos, dir)
+        }
+    } else {
+        assert_eq!(next_val, '.');
+        set(grid, pos, '.');
+        set(grid, new_pos, cur_val);
+        return true;
+    };
+
+    if success {
+        set(grid, pos, '.');
+        set(grid, new_pos, cur_val);
+    }
+
+    success
+}
+
+fn solve(mut pos: Point, mut grid: Grid<char>, moves: &[Point], c: char) -> i32 {
+    for &m in moves {
+        let mut g = grid.clone();
+        let success = do_move(&mut g, pos, m);
+        if success {
+            grid = g;
+            pos = pos + m;
+        }
+    }
+
+    enumerate(&grid)
+        .map(|(pos, &val)| if val == c { pos.0 + 100 * pos.1 } else { 0 })
+        .sum()
+}
+
+fn part1((start, grid, moves): &(Point, Grid<char>, Vec<Point>)) -> i32 {
+    solve(*start, grid.clone(), moves, 'O')
+}
+
+fn part2((start, grid, moves): &(Point, Grid<char>, Vec<Point>)) -> i32 {
+    let grid = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|&c| match c {
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    c => [c, c],
+                })
+                .collect()
+        })
+        .collect();
+
+    solve(Point(start.0 * 2, start.1), grid, moves, '[')
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = (Point, Grid<char>, Vec<Point>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    /// A rectangular warehouse of known tiles with walls all around, so that
+    /// moves never leave it, and exactly one robot, followed by known moves
+    fn validate(input: &str) -> Vec<ParseError> {
+        let (map, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+        let moves_line = map.lines().count() + 2;
+
+        let mut errors = validate::rectangular(DAY, map, 1);
+        errors.extend(validate::only(DAY, map, 1, |c| "#.O@".contains(c), TILES));
+        errors.extend(validate::walled(DAY, map, 1, '#'));
+        errors.extend(validate::exactly_one(DAY, map, 1, '@'));
+        errors.extend(validate::only(
+            DAY,
+            moves,
+            moves_line,
+            |c| "<>^v".contains(c),
+            MOVES,
+        ));
+        errors
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    /// A `size` × `size` warehouse surrounded by walls, with a few more walls
+    /// and lots of boxes inside. Like in the real input it's followed by 8
+    /// moves per tile, in lines of 1000.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(3);
+
+        let mut grid = vec![vec!['#'; size]; size];
+        for line in &mut grid[1..size - 1] {
+            for cell in &mut line[1..size - 1] {
+                *cell = match rng.u8(..10) {
+                    0 => '#',
+                    1..=3 => 'O',
+                    _ => '.',
+                };
+            }
+        }
+        let robot = Point(rng.i32(1..size as i32 - 1), rng.i32(1..size as i32 - 1));
+        set(&mut grid, robot, '@');
+
+        let moves = (0..8 * size * size)
+            .map(|_| ['^', '>', 'v', '<'][rng.usize(..4)])
+            .collect::<Vec<_>>();
+        let moves = moves
+            .chunks(1000)
+            .map(|line| line.iter().collect::<String>() + "\n");
+
+        Some(grid_to_string(&grid) + "\n" + &moves.collect::<String>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST1: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+    const TEST2: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST1).unwrap()), 2028);
+        assert_eq!(part1(&parse(TEST2).unwrap()), 10092);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST2).unwrap()), 9021);
+    }
+
+    #[test]
+    fn unknown_tiles_and_moves() {
+        let input = "#####\n#@x.#\n#####\n\n>x>\n";
+
+        let errors = Day15::validate(input);
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column))
+                .collect::<Vec<_>>(),
+            [(2, 3), (5, 2)]
+        );
+        assert_eq!(
+            errors[1].to_string(),
+            "day 15, line 5, column 2: expected a move, one of `<>^v`, found `x`"
+        );
+        assert_eq!(parse(input).unwrap_err(), errors[0]);
+        assert_eq!(parse("#####\n#@..#\n#####\n\n>x>").unwrap_err(), errors[1]);
+    }
+}
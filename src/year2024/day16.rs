@@ -0,0 +1,273 @@
+use std::collections::{HashMap, HashSet};
+
+use fastrand::Rng;
+
+use crate::{
+    error::{NoAnswer, ParseError, Result},
+    solution::{Solution, Solved},
+    utils::{get, grid_to_string, pop_min, read_grid, set, Grid, Point},
+    validate,
+};
+
+fn children(pos: Point, dir: Point, grid: &Grid<char>) -> Vec<(i32, (Point, Point))> {
+    let mut res = vec![];
+    let mut inner_neighbours = |cost, pos, dir| {
+        if let Some(val) = get(grid, pos) {
+            if val != '#' {
+                res.push((cost, (pos, dir)));
+            }
+        }
+    };
+
+    inner_neighbours(1, pos + dir, dir);
+    inner_neighbours(1000, pos, dir.rotate_dir(true));
+    inner_neighbours(1000, pos, dir.rotate_dir(false));
+
+    res
+}
+
+/// (position, direction)
+type State = (Point, Point);
+
+fn dijkstra(start: State, grid: &Grid<char>) -> (HashMap<State, i32>, HashMap<State, Vec<State>>) {
+    let mut costs = HashMap::new();
+    costs.insert(start, 0);
+
+    let mut parents = HashMap::new();
+
+    let mut queue = vec![(0, start)];
+
+    while let Some((parent_cost, parent)) = pop_min(&mut queue, |x| x.0) {
+        for (child_cost, child) in children(parent.0, parent.1, grid) {
+            let new_cost = parent_cost + child_cost;
+
+            if let Some(&d) = costs.get(&child) {
+                if d > new_cost {
+                    costs.insert(child, new_cost);
+                    parents.insert(child, vec![parent]);
+
+                    queue.push((new_cost, child));
+                } else if d == new_cost {
+                    parents.entry(child).and_modify(|v| v.push(parent));
+                }
+            } else {
+                costs.insert(child, new_cost);
+                parents.insert(child, vec![parent]);
+
+                queue.push((new_cost, child));
+            }
+        }
+    }
+
+    (costs, parents)
+}
+
+fn parse(input: &str) -> Result<(Point, Point, Grid<char>)> {
+    let mut start_pos = Point::zero();
+    let mut end_pos = Point::zero();
+    let grid = read_grid(input, |pos, c| {
+        if c == 'S' {
+            start_pos = pos;
+        } else if c == 'E' {
+            end_pos = pos;
+        }
+
+        c
+    });
+
+    Ok((start_pos, end_pos, grid))
+}
+
+/// Lowest cost of reaching `end` facing any direction, `None` if it can't be
+/// reached
+fn min_cost(costs: &HashMap<State, i32>, end: Point) -> Option<i32> {
+    Point::orth_dirs()
+        .into_iter()
+        .filter_map(|dir| costs.get(&(end, dir)).copied())
+        .min()
+}
+
+fn part1(&(start, end, ref grid): &(Point, Point, Grid<char>)) -> Option<i32> {
+    let (costs, _) = dijkstra((start, Point::EAST), grid);
+
+    min_cost(&costs, end)
+}
+
+fn part2(&(start, end, ref grid): &(Point, Point, Grid<char>)) -> Option<i32> {
+    let (costs, parents) = dijkstra((start, Point::EAST), grid);
+
+    let min_cost = min_cost(&costs, end)?;
+
+    let mut seen = HashSet::new();
+
+    for dir in Point::orth_dirs() {
+        if let Some(&cost) = costs.get(&(end, dir)) {
+            if cost == min_cost {
+                if let Some(p) = parents.get(&(end, dir)) {
+                    let mut queue = p.clone();
+
+                    while let Some(parent) = queue.pop() {
+                        if seen.contains(&parent) {
+                            continue;
+                        }
+                        seen.insert(parent);
+                        for grand_parent in parents.get(&parent).unwrap_or(&vec![]) {
+                            queue.push(*grand_parent);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Some(
+        seen.iter()
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<_>>()
+            .len() as i32
+            + 1,
+    )
+}
+
+fn unreachable_end() -> NoAnswer {
+    NoAnswer::new(Day16::DAY, "the end can't be reached from the start")
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 16;
+
+    type Input = (Point, Point, Grid<char>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    /// A rectangular map with exactly one start and one end
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut errors = validate::rectangular(Self::DAY, input, 1);
+        errors.extend(validate::exactly_one(Self::DAY, input, 1, 'S'));
+        errors.extend(validate::exactly_one(Self::DAY, input, 1, 'E'));
+        errors
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).ok_or_else(unreachable_end)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).ok_or_else(unreachable_end)?.into())
+    }
+
+    /// A `size` × `size` maze (rounded up to an odd size) from S in the bottom
+    /// left to E in the top right corner. It's carved out like a spanning tree,
+    /// with some more walls knocked out so that there are several best paths.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(5) | 1;
+        let mut grid = vec![vec!['#'; size]; size];
+
+        // tiles at odd coordinates are open, the walls between them are
+        // removed depth first
+        let mut stack = vec![Point(1, 1)];
+        set(&mut grid, Point(1, 1), '.');
+        while let Some(&pos) = stack.last() {
+            let mut dirs = Point::orth_dirs();
+            rng.shuffle(&mut dirs);
+
+            match dirs
+                .into_iter()
+                .find(|&dir| get(&grid, pos + 2 * dir) == Some('#'))
+            {
+                Some(dir) => {
+                    set(&mut grid, pos + dir, '.');
+                    set(&mut grid, pos + 2 * dir, '.');
+                    stack.push(pos + 2 * dir);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        for y in 1..size - 1 {
+            for x in 1..size - 1 {
+                let pos = Point(x as i32, y as i32);
+                let between =
+                    |dir| get(&grid, pos + dir) == Some('.') && get(&grid, pos - dir) == Some('.');
+                if (between(Point::EAST) || between(Point::SOUTH)) && rng.u8(..10) == 0 {
+                    set(&mut grid, pos, '.');
+                }
+            }
+        }
+
+        set(&mut grid, Point(1, size as i32 - 2), 'S');
+        set(&mut grid, Point(size as i32 - 2, 1), 'E');
+
+        Some(grid_to_string(&grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST1: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const TEST2: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST1).unwrap()), Some(7036));
+        assert_eq!(part1(&parse(TEST2).unwrap()), Some(11048));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST1).unwrap()), Some(45));
+        assert_eq!(part2(&parse(TEST2).unwrap()), Some(64));
+    }
+
+    #[test]
+    fn walled_off_end() {
+        let input = parse("#######\n#S.#.E#\n#######").unwrap();
+
+        for error in [Day16::part1(&input), Day16::part2(&input)] {
+            assert_eq!(
+                error.unwrap_err().to_string(),
+                "day 16: no answer, the end can't be reached from the start"
+            );
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    error::{NoAnswer, Result},
+    parse::Span,
+    solution::{Solution, Solved},
+};
+
+const DAY: u32 = 17;
+
+#[derive(Clone)]
+pub struct Program {
+    a: u64,
+    b: u64,
+    c: u64,
+    commands: Vec<u8>,
+    output: Vec<u8>,
+}
+
+impl Program {
+    fn run(&mut self) {
+        let mut pointer = 0;
+
+        while pointer < self.commands.len() - 1 {
+            let opcode = self.commands[pointer];
+            let instruction = Instruction::new(opcode);
+            let operand = instruction.operand(self.commands[pointer + 1], self);
+
+            instruction.execute(operand, self, &mut pointer);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.a = 0;
+        self.b = 0;
+        self.c = 0;
+        self.output.clear();
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(unused)]
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Instruction {
+    /// `opcode` has to be a 3-bit number, `parse` checks this
+    fn new(opcode: u8) -> Self {
+        assert!(opcode < 8);
+        unsafe { std::mem::transmute(opcode) }
+    }
+
+    /// Whether the operand is a combo operand rather than a literal one. `bxc`
+    /// ignores its operand, so it's read as a literal.
+    fn takes_combo(self) -> bool {
+        !matches!(self, Instruction::Bxl | Instruction::Jnz | Instruction::Bxc)
+    }
+
+    fn operand(&self, index: u8, program: &Program) -> u64 {
+        if !self.takes_combo() {
+            return index as _;
+        }
+
+        match index {
+            x @ 0..=3 => x as _,
+            4 => program.a,
+            5 => program.b,
+            6 => program.c,
+            _ => unreachable!(),
+        }
+    }
+
+    fn execute(self, operand: u64, program: &mut Program, pointer: &mut usize) {
+        match self {
+            Instruction::Adv => program.a = divide(program.a, operand),
+            Instruction::Bxl => program.b ^= operand,
+            Instruction::Bst => program.b = operand % 8,
+            Instruction::Jnz => {
+                if program.a != 0 {
+                    *pointer = operand as _;
+                } else {
+                    *pointer += 2;
+                }
+            }
+            Instruction::Bxc => program.b ^= program.c,
+            Instruction::Out => program.output.push((operand % 8) as _),
+            Instruction::Bdv => program.b = divide(program.a, operand),
+            Instruction::Cdv => program.c = divide(program.a, operand),
+        }
+
+        if self != Instruction::Jnz {
+            *pointer += 2;
+        }
+    }
+}
+
+/// `a / 2^operand`, which is 0 once the power doesn't fit in a `u64`
+fn divide(a: u64, operand: u64) -> u64 {
+    u32::try_from(operand)
+        .ok()
+        .and_then(|shift| a.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+fn parse(input: &str) -> Result<Program> {
+    let (registers, program) = Span::new(DAY, input).split_once("\n\n")?;
+    let end = registers.skip(registers.as_str().len());
+    let mut lines = registers.lines();
+    let mut register = |name: &str| -> Result<u64> {
+        let line = lines.next().unwrap_or(end);
+        line.strip_prefix(&format!("Register {name}: "))?.parse()
+    };
+    let a = register("A")?;
+    let b = register("B")?;
+    let c = register("C")?;
+
+    let program = program.trim().strip_prefix("Program: ")?;
+    let numbers = program.split(",").collect::<Vec<_>>();
+    let commands = numbers
+        .iter()
+        .map(|x| match x.parse::<u8>()? {
+            // every opcode and operand is a 3-bit number
+            n @ 0..=7 => Ok(n),
+            _ => Err(x.error("a number from 0 to 7")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the instruction pointer only ever lands on opcodes, so each operand
+    // belongs to the opcode before it
+    for (i, pair) in commands.chunks_exact(2).enumerate() {
+        let instruction = Instruction::new(pair[0]);
+        let operand = numbers[2 * i + 1];
+        if instruction.takes_combo() && pair[1] == 7 {
+            return Err(operand.error("a combo operand from 0 to 6"));
+        }
+        if instruction == Instruction::Jnz && pair[1] % 2 == 1 {
+            return Err(operand.error("an even jump target"));
+        }
+    }
+
+    Ok(Program {
+        a,
+        b,
+        c,
+        commands,
+        output: vec![],
+    })
+}
+
+fn part1(program: &Program) -> String {
+    let mut program = program.clone();
+    program.run();
+
+    program
+        .output
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `None` if no value of register A makes the program output itself
+fn part2(program: &Program) -> Option<u64> {
+    // 2,4,1,5,7,5,0,3,1,6,4,3,5,5,3,0
+    //
+    // (2 4) => b = a % 8
+    // (1 5) => b = b ^ 5
+    // (7 5) => c = a // 2**b
+    // (0 3) => a = a // 2**3
+    // (1 6) => b = b ^ 6
+    // (4 3) => b = b ^ c
+    // (5 5) => output.push(b % 8)	(*)
+    // (3 0) => repeat if a != 0
+
+    // so:
+    // b = a % 8
+    // b = b ^ 5
+    // c = a >> b
+    // a = a >> 3
+    // b = b ^ 6
+    // b = b ^ c
+    // out(b % 8)
+    // repeat if a != 0
+    //
+    // which simplifies to:
+    // while a != 0 {
+    //     out(f(a) % 8)
+    //     a = a >> 3
+    // }
+    //
+    // so, the plan is to iteratively find each 3 bit chunk of A
+
+    let mut program = program.clone();
+    let target_output = program.commands.clone();
+    let n = target_output.len();
+
+    let mut parts = vec![0];
+
+    for i in (0..n).rev() {
+        let mut new_parts = vec![];
+
+        for part in &parts {
+            for next_part in 0..8 {
+                let a = (part << 3) + next_part;
+                program.a = a;
+                program.run();
+
+                if program.output == target_output[i..] {
+                    new_parts.push(a);
+                }
+
+                program.reset();
+            }
+        }
+
+        parts = new_parts;
+    }
+
+    parts.into_iter().min()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        let a = part2(input)
+            .ok_or_else(|| NoAnswer::new(DAY, "no value of A makes the program output itself"))?;
+        Ok(a.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST1: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    const TEST2: &str = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST1).unwrap()), "4,6,3,5,6,3,5,2,1,0")
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST2).unwrap()), Some(117440));
+
+        let error = Day17::part2(&parse(TEST1).unwrap()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 17: no answer, no value of A makes the program output itself"
+        );
+    }
+
+    #[test]
+    fn operands() {
+        let program =
+            |program| format!("Register A: 1\nRegister B: 64\nRegister C: 0\n\nProgram: {program}");
+
+        assert_eq!(
+            parse(&program("2,7")).err().unwrap().to_string(),
+            "day 17, line 5, column 12: expected a combo operand from 0 to 6, found `7`"
+        );
+        assert_eq!(
+            parse(&program("0,1,3,1")).err().unwrap().to_string(),
+            "day 17, line 5, column 16: expected an even jump target, found `1`"
+        );
+        // dividing by 2^64 leaves nothing
+        assert_eq!(part1(&parse(&program("0,5,5,4")).unwrap()), "0");
+    }
+}
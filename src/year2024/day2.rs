@@ -0,0 +1,224 @@
+use fastrand::Rng;
+
+use crate::{
+    error::{ParseError, Result},
+    input::normalize,
+    parse::Span,
+    solution::{Solution, Solved, Variant},
+};
+
+const DAY: u32 = 2;
+
+pub struct Reports {
+    /// `part1` scans the input bytes itself instead of using the parsed levels
+    raw: String,
+    levels: Vec<Vec<i32>>,
+}
+
+fn parse(input: &str) -> Result<Reports> {
+    let input = normalize(input);
+
+    // `part1` only understands digits, with single spaces between levels
+    let bytes = input.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        let ok = match b {
+            b'0'..=b'9' | b'\n' => true,
+            b' ' => {
+                i > 0
+                    && bytes[i - 1].is_ascii_digit()
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            }
+            _ => false,
+        };
+        if !ok {
+            let expected = "a digit, or a single space between levels";
+            return Err(ParseError::at(DAY, &input, i, expected));
+        }
+    }
+
+    let levels = Span::new(DAY, &input)
+        .lines()
+        .map(|line| line.split_whitespace().map(|x| x.parse()).collect())
+        .collect::<Result<_>>()?;
+
+    Ok(Reports {
+        raw: input.into_owned(),
+        levels,
+    })
+}
+
+fn part1_naive(reports: &[Vec<i32>]) -> i32 {
+    reports.iter().filter(|r| is_safe(r)).count() as i32
+}
+
+/// Relies on the input ending with a newline, which completes the last
+/// report, and on levels being single spaced, both of which `parse` ensures
+fn part1(input: &str) -> i32 {
+    let bytes = input.as_bytes();
+
+    let mut count = 0;
+
+    let mut is_safe = true;
+    let mut is_descending = None;
+    let mut level = 0;
+    // -1 before the first level of a report
+    let mut prev = -1i32;
+
+    for &b in bytes {
+        // space/newline means level is fully read
+        if is_safe && (b == 10 || b == 32) {
+            match (is_descending, prev) {
+                (None, x) if x >= 0 => is_descending = Some(level < x),
+                _ => (),
+            }
+            if prev >= 0 {
+                let diff = (level - prev).abs();
+                if diff > 3 || diff == 0 || is_descending.is_some_and(|desc| (level < prev) != desc)
+                {
+                    is_safe = false;
+                }
+            }
+
+            (prev, level) = (level, 0);
+        }
+        // newline
+        if b == 10 {
+            count += is_safe as i32;
+            is_safe = true;
+            level = 0;
+            prev = -1;
+            is_descending = None;
+        }
+        // non-digit, or the rest of a report that is already unsafe
+        if b < 48 || !is_safe {
+            continue;
+        }
+
+        level *= 10;
+        level += (b & 0xf) as i32;
+    }
+
+    count
+}
+
+fn part2(reports: &[Vec<i32>]) -> i32 {
+    reports
+        .iter()
+        .filter(|r| is_safe(r) || test(r.to_vec()))
+        .count() as i32
+}
+
+fn test(mut l: Vec<i32>) -> bool {
+    for skip in 0..(l.len()) {
+        let removed = l.remove(skip);
+
+        if is_safe(&l) {
+            return true;
+        }
+
+        l.insert(skip, removed);
+    }
+
+    false
+}
+
+/// Reports of fewer than two levels have no steps, so they're safe
+fn is_safe(l: &[i32]) -> bool {
+    if l.len() < 2 {
+        return true;
+    }
+
+    let mut safe = true;
+    let sign = (l[0] - l[1]).signum();
+
+    for (idx, x) in l.iter().enumerate() {
+        if idx == l.len() - 1 {
+            continue;
+        }
+        let diff = x - l[idx + 1];
+        if diff.signum() != sign || diff.abs() > 3 || diff.abs() < 1 {
+            safe = false;
+            break;
+        }
+    }
+    safe
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Reports;
+
+    fn parse(input: &str) -> Result<Reports> {
+        parse(input)
+    }
+
+    fn part1(reports: &Reports) -> Solved {
+        Ok(part1(&reports.raw).into())
+    }
+
+    fn part2(reports: &Reports) -> Solved {
+        Ok(part2(&reports.levels).into())
+    }
+
+    fn variants() -> Vec<Variant<Reports>> {
+        vec![Variant::new(1, "naive", |reports| {
+            Ok(part1_naive(&reports.levels).into())
+        })]
+    }
+
+    /// Reports of 5 to 8 levels between 1 and 99 with steps of up to 4 in
+    /// either direction, so that some are safe and some are not
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut level = rng.i32(1..=99);
+            let direction = if rng.bool() { 1 } else { -1 };
+            let levels = (0..rng.usize(5..=8))
+                .map(|_| {
+                    let current = level;
+                    level = (level + direction * rng.i32(-1..=4)).clamp(1, 99);
+                    current.to_string()
+                })
+                .collect::<Vec<_>>();
+
+            input += &levels.join(" ");
+            input += "\n";
+        }
+
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_reports() {
+        let reports = parse("5\n1 9\n").unwrap();
+
+        assert_eq!(part1(&reports.raw), 1);
+        assert_eq!(part1_naive(&reports.levels), 1);
+        assert_eq!(part2(&reports.levels), 2);
+    }
+
+    #[test]
+    fn variants_agree() {
+        let reports = parse("7 6 4 2 1\r\n1 2 7 8 9\r\n0 5\r\n0 1 3\r\n1 3 2 4 5").unwrap();
+        assert_eq!(part1(&reports.raw), 2);
+        assert_eq!(part1_naive(&reports.levels), 2);
+
+        for (input, column) in [("1 2\n3\t4", 2), ("1 -2", 2), ("1  2", 2), ("+1 2", 1)] {
+            let error = parse(input).err().unwrap();
+            assert_eq!(
+                (error.line, error.column),
+                (input.lines().count(), column),
+                "{input:?}"
+            );
+        }
+    }
+}
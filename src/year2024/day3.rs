@@ -0,0 +1,75 @@
+use regex::Regex;
+
+use crate::{
+    error::Result,
+    solution::{Solution, Solved},
+};
+
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>> {
+    // both numbers have 1-3 digits, so parsing can't fail
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+
+    Ok(re
+        .captures_iter(input)
+        .map(|c| match (c.get(1), c.get(2)) {
+            (Some(a), Some(b)) => {
+                Instruction::Mul(a.as_str().parse().unwrap(), b.as_str().parse().unwrap())
+            }
+            _ if &c[0] == "do()" => Instruction::Do,
+            _ => Instruction::Dont,
+        })
+        .collect())
+}
+
+fn part1(instructions: &[Instruction]) -> i32 {
+    instructions
+        .iter()
+        .map(|i| match i {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn part2(instructions: &[Instruction]) -> i32 {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for i in instructions {
+        match i {
+            Instruction::Mul(a, b) if enabled => sum += a * b,
+            Instruction::Mul(..) => (),
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 3;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    error::{ParseError, Result},
+    solution::{Solution, Solved},
+    utils::{read_grid, Grid},
+    validate,
+};
+
+fn parse(input: &str) -> Result<Grid<char>> {
+    Ok(read_grid(input, |_, c| c))
+}
+
+fn get(grid: &[Vec<char>], pos: (i32, i32)) -> Option<char> {
+    if pos.0 < 0 || pos.1 < 0 {
+        return None;
+    }
+
+    grid.get(pos.1 as usize)
+        .and_then(|row| row.get(pos.0 as usize).cloned())
+}
+
+fn search(grid: &[Vec<char>], target: char, pos: (i32, i32), dir: (i32, i32)) -> i32 {
+    match get(grid, pos) {
+        Some(c) if c == target => {
+            let next_c = match c {
+                'X' => 'M',
+                'M' => 'A',
+                'A' => 'S',
+                'S' => return 1,
+                _ => unreachable!(),
+            };
+
+            let new_pos = (pos.0 + dir.0, pos.1 + dir.1);
+            search(grid, next_c, new_pos, dir)
+        }
+        _ => 0,
+    }
+}
+
+fn part1(grid: &[Vec<char>]) -> i32 {
+    let rows = grid.len() as i32;
+    let cols = grid[0].len() as i32;
+
+    let mut count = 0;
+
+    for y in 0..rows {
+        for x in 0..cols {
+            for dir in [
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+            ] {
+                count += search(grid, 'X', (x, y), dir);
+            }
+        }
+    }
+
+    count
+}
+
+fn is_x_mas(grid: &[Vec<char>], pos: (i32, i32)) -> bool {
+    if get(grid, pos).is_none_or(|c| c != 'A') {
+        return false;
+    }
+
+    // the corners, which are `None` off the grid
+    let (x, y) = pos;
+    let at = |dx, dy| get(grid, (x + dx, y + dy));
+    let diagonal = |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+
+    // M . .     S . .
+    // . A . or  . A .
+    // . . S     . . M
+    if !diagonal(at(-1, -1), at(1, 1)) {
+        return false;
+    }
+
+    // . . M     . . S
+    // . A . or  . A .
+    // S . .     M . .
+    diagonal(at(1, -1), at(-1, 1))
+}
+
+fn part2(grid: &[Vec<char>]) -> i32 {
+    let rows = grid.len() as i32;
+    let cols = grid[0].len() as i32;
+
+    let mut count = 0;
+
+    for y in 1..rows - 1 {
+        for x in 1..cols - 1 {
+            count += is_x_mas(grid, (x, y)) as i32;
+        }
+    }
+
+    count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate::rectangular(Self::DAY, input, 1)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+}
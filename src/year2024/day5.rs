@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use fastrand::Rng;
+
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved},
+};
+
+const DAY: u32 = 5;
+
+pub type Rules = HashMap<i32, Vec<i32>>;
+
+fn parse_rules(s: Span) -> Result<Rules> {
+    let mut preceders = HashMap::<i32, Vec<i32>>::new();
+    for line in s.lines() {
+        let (a, b) = line.split_once("|")?;
+        let (a, b) = (a.parse()?, b.parse()?);
+        preceders
+            .entry(a)
+            .and_modify(|v| v.push(b))
+            .or_insert(vec![b]);
+    }
+
+    Ok(preceders)
+}
+
+fn parse_updates(s: Span) -> Result<Vec<Vec<i32>>> {
+    s.lines()
+        .map(|line| line.split(",").map(|x| x.parse::<i32>()).collect())
+        .collect()
+}
+
+fn is_valid(update: &[i32], rules: &Rules) -> bool {
+    for (i, x) in update.iter().enumerate() {
+        if let Some(r) = rules.get(x) {
+            for y in &update[..i] {
+                if r.contains(y) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+fn sort(mut update: Vec<i32>, rules: &Rules) -> Vec<i32> {
+    let len = update.len();
+
+    for i in 0..len {
+        let x = update[i];
+        if let Some(r) = rules.get(&x) {
+            for j in 0..i {
+                let y = update[j];
+                if r.contains(&y) {
+                    update.swap(i, j);
+                }
+            }
+        }
+    }
+
+    update
+}
+
+fn parse(input: &str) -> Result<(Rules, Vec<Vec<i32>>)> {
+    let (rules, updates) = Span::new(DAY, input).split_once("\n\n")?;
+    Ok((parse_rules(rules)?, parse_updates(updates)?))
+}
+
+fn part1((rules, updates): &(Rules, Vec<Vec<i32>>)) -> i32 {
+    updates
+        .iter()
+        .map(|update| update[update.len() / 2] * (is_valid(update, rules) as i32))
+        .sum()
+}
+
+fn part2((rules, updates): &(Rules, Vec<Vec<i32>>)) -> i32 {
+    updates
+        .iter()
+        .filter(|update| !is_valid(update, rules))
+        .map(|update| sort(update.clone(), rules))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = (Rules, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    /// `size` updates of 5 to 23 pages, about half of them in the right order.
+    /// Like in the real input there are rules for every pair of 49 pages.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut pages = (10..100).collect::<Vec<_>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+
+        let mut rules = vec![];
+        for (i, a) in pages.iter().enumerate() {
+            for b in &pages[i + 1..] {
+                rules.push(format!("{a}|{b}\n"));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let mut input = rules.concat() + "\n";
+        for _ in 0..size {
+            let len = rng.usize(2..=11) * 2 + 1;
+            let mut update = rng.choose_multiple(0..pages.len(), len);
+            if rng.bool() {
+                update.sort();
+            } else {
+                rng.shuffle(&mut update);
+            }
+
+            let update = update.iter().map(|&i| pages[i].to_string());
+            input += &update.collect::<Vec<_>>().join(",");
+            input += "\n";
+        }
+
+        Some(input)
+    }
+}
@@ -0,0 +1,362 @@
+use fastrand::Rng;
+use rayon::prelude::*;
+
+use crate::{
+    error::{NoAnswer, ParseError, Result},
+    solution::{Solution, Solved, Variant},
+    utils::{get, get_mut, grid_to_string, read_grid, set, Grid, Point},
+    validate,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    // keep track of the previous direction
+    Visited(u8),
+    Obstacle,
+    Unvisited,
+}
+
+impl Cell {
+    pub fn add_dir(&mut self, dir: (i32, i32)) {
+        if let Cell::Visited(mask) = self {
+            *mask |= dir_mask(dir);
+        } else {
+            *self = Cell::Visited(dir_mask(dir));
+        }
+    }
+
+    /// Whether the guard was here before facing `dir`
+    pub fn has_dir(self, dir: (i32, i32)) -> bool {
+        matches!(self, Cell::Visited(mask) if mask & dir_mask(dir) != 0)
+    }
+}
+
+/// Turns the guard at `pos` to the right and records the new direction, or
+/// returns `None` if it faced that way there before. A guard boxed in on all
+/// four sides ends up here instead of turning forever.
+fn turn(grid: &mut Grid<Cell>, pos: Point, dir: (i32, i32)) -> Option<(i32, i32)> {
+    let dir = next_dir(dir);
+    let cell = get_mut(grid, pos).unwrap();
+    if cell.has_dir(dir) {
+        return None;
+    }
+    cell.add_dir(dir);
+    Some(dir)
+}
+
+fn next_dir(dir: (i32, i32)) -> (i32, i32) {
+    match dir {
+        (0, -1) => (1, 0),
+        (1, 0) => (0, 1),
+        (0, 1) => (-1, 0),
+        (-1, 0) => (0, -1),
+        _ => unreachable!(),
+    }
+}
+
+fn dir_mask(dir: (i32, i32)) -> u8 {
+    match dir {
+        (0, -1) => 1,
+        (1, 0) => 2,
+        (0, 1) => 4,
+        (-1, 0) => 8,
+        _ => unreachable!(),
+    }
+}
+
+fn parse(input: &str) -> Result<(Point, Grid<Cell>)> {
+    let mut start_pos = Point::zero();
+    let grid = read_grid(input, |pos, c| {
+        if c == '^' {
+            start_pos = pos;
+        }
+        match c {
+            '#' => Cell::Obstacle,
+            '^' => Cell::Visited(dir_mask((0, -1))),
+            _ => Cell::Unvisited,
+        }
+    });
+
+    Ok((start_pos, grid))
+}
+
+/// `None` if the guard never leaves the map
+fn part1((start, grid): &(Point, Grid<Cell>)) -> Option<i32> {
+    let (mut pos, mut grid) = (*start, grid.clone());
+    let mut dir = (0, -1);
+
+    let mut count = 1;
+
+    while let Some(next_cell) = get(&grid, pos + dir) {
+        if next_cell == Cell::Obstacle {
+            dir = turn(&mut grid, pos, dir)?;
+            continue;
+        }
+        if next_cell.has_dir(dir) {
+            return None;
+        }
+        count += (next_cell == Cell::Unvisited) as i32;
+        pos = pos + dir;
+        get_mut(&mut grid, pos).unwrap().add_dir(dir);
+    }
+
+    Some(count)
+}
+
+/// `None` if the guard never leaves the map, even without another obstacle
+fn part2((start, grid): &(Point, Grid<Cell>)) -> Option<i32> {
+    let (mut pos, mut grid) = (*start, grid.clone());
+    let mut dir = (0, -1);
+    let mut count = 0;
+
+    while let Some(next_cell) = get(&grid, pos + dir) {
+        let next_pos = pos + dir;
+
+        if next_cell == Cell::Obstacle {
+            dir = turn(&mut grid, pos, dir)?;
+            continue;
+        }
+        if next_cell.has_dir(dir) {
+            return None;
+        }
+
+        if next_cell == Cell::Unvisited {
+            let mut alt_grid = grid.clone();
+            set(&mut alt_grid, next_pos, Cell::Obstacle);
+            let mut alt_pos = pos;
+            let mut alt_dir = next_dir(dir);
+
+            while let Some(next_cell) = get(&alt_grid, alt_pos + alt_dir) {
+                if next_cell == Cell::Obstacle {
+                    match turn(&mut alt_grid, alt_pos, alt_dir) {
+                        Some(dir) => alt_dir = dir,
+                        None => {
+                            count += 1;
+                            break;
+                        }
+                    }
+                    continue;
+                }
+
+                if next_cell.has_dir(alt_dir) {
+                    count += 1;
+                    break;
+                }
+                alt_pos = alt_pos + alt_dir;
+                get_mut(&mut alt_grid, alt_pos).unwrap().add_dir(alt_dir);
+            }
+        }
+
+        pos = pos + dir;
+        get_mut(&mut grid, pos).unwrap().add_dir(dir);
+    }
+
+    Some(count)
+}
+
+/// Whether the guard gets stuck in a loop after adding an obstacle at `obstacle`
+fn loops(grid: &Grid<Cell>, start: Point, obstacle: Point) -> bool {
+    let (mut pos, mut grid) = (start, grid.clone());
+    set(&mut grid, obstacle, Cell::Obstacle);
+    let mut dir = (0, -1);
+
+    while let Some(next_cell) = get(&grid, pos + dir) {
+        if next_cell == Cell::Obstacle {
+            match turn(&mut grid, pos, dir) {
+                Some(next) => dir = next,
+                None => return true,
+            }
+            continue;
+        }
+
+        if next_cell.has_dir(dir) {
+            return true;
+        }
+        pos = pos + dir;
+        get_mut(&mut grid, pos).unwrap().add_dir(dir);
+    }
+
+    false
+}
+
+/// Like `part2`, but every obstacle on the guard's path is tried separately
+/// from the start, in parallel
+fn part2_parallel((start, grid): &(Point, Grid<Cell>)) -> Option<i32> {
+    let (mut pos, mut path) = (*start, grid.clone());
+    let mut dir = (0, -1);
+    let mut candidates = vec![];
+
+    while let Some(next_cell) = get(&path, pos + dir) {
+        if next_cell == Cell::Obstacle {
+            dir = turn(&mut path, pos, dir)?;
+            continue;
+        }
+        if next_cell.has_dir(dir) {
+            return None;
+        }
+        pos = pos + dir;
+        if next_cell == Cell::Unvisited {
+            candidates.push(pos);
+        }
+        get_mut(&mut path, pos).unwrap().add_dir(dir);
+    }
+
+    Some(
+        candidates
+            .par_iter()
+            .filter(|&&obstacle| loops(grid, *start, obstacle))
+            .count() as i32,
+    )
+}
+
+/// Whether the guard walks off the map instead of getting stuck in a loop
+fn leaves(grid: &Grid<char>, mut pos: Point) -> bool {
+    let mut dir = (0, -1);
+
+    // every step changes position or direction, so after more steps than
+    // there are combinations of both the guard has to be in a loop
+    for _ in 0..=4 * grid.len() * grid[0].len() {
+        match get(grid, pos + dir) {
+            None => return true,
+            Some('#') => dir = next_dir(dir),
+            Some(_) => pos = pos + dir,
+        }
+    }
+
+    false
+}
+
+fn stays() -> NoAnswer {
+    NoAnswer::new(Day6::DAY, "the guard never leaves the map")
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 6;
+
+    type Input = (Point, Grid<Cell>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    /// A rectangular map with exactly one guard
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut errors = validate::rectangular(Self::DAY, input, 1);
+        errors.extend(validate::exactly_one(Self::DAY, input, 1, '^'));
+        errors
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).ok_or_else(stays)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).ok_or_else(stays)?.into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![Variant::new(2, "parallel", |input| {
+            Ok(part2_parallel(input).ok_or_else(stays)?.into())
+        })]
+    }
+
+    /// A `size` × `size` map with obstacles on about one in 30 cells, which
+    /// the guard walks off like in the real puzzle. The start has at most two
+    /// obstacles next to it, so no obstacle added in part 2 boxes it in.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+
+        loop {
+            let mut grid = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.u8(..30) == 0 { '#' } else { '.' })
+                        .collect()
+                })
+                .collect::<Grid<char>>();
+            let start = Point(rng.i32(0..size as i32), rng.i32(0..size as i32));
+            set(&mut grid, start, '^');
+
+            let blocked = Point::orth_dirs()
+                .into_iter()
+                .filter(|&dir| get(&grid, start + dir) == Some('#'))
+                .count();
+            if blocked < 3 && leaves(&grid, start) {
+                return Some(grid_to_string(&grid));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            part1(
+                &parse(
+                    "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+                )
+                .unwrap()
+            ),
+            Some(41)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            part2(
+                &parse(
+                    "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+                )
+                .unwrap()
+            ),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn boxed_in_start() {
+        let input = parse(".#.\n#^#\n...").unwrap();
+
+        assert_eq!(part2(&input), Some(1));
+        assert_eq!(part2_parallel(&input), Some(1));
+    }
+
+    #[test]
+    fn guard_never_leaves() {
+        let input = parse(".#...\n....#\n#....\n.^.#.").unwrap();
+
+        assert_eq!(part1(&input), None);
+        assert_eq!(part2(&input), None);
+        assert_eq!(part2_parallel(&input), None);
+        assert_eq!(
+            Day6::part1(&input).unwrap_err().to_string(),
+            "day 6: no answer, the guard never leaves the map"
+        );
+    }
+}
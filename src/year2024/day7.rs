@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use rayon::prelude::*;
+
+use crate::{
+    error::Result,
+    parse::Span,
+    solution::{Solution, Solved, Variant},
+};
+
+const DAY: u32 = 7;
+
+fn parse(input: &str) -> Result<Vec<(i64, VecDeque<i64>)>> {
+    Span::new(DAY, input)
+        .lines()
+        .map(|line| {
+            let (val, parts) = line.split_once(": ")?;
+            let val = val.parse()?;
+            let parts = parts
+                .split_whitespace()
+                .map(|x| x.parse())
+                .collect::<Result<_>>()?;
+
+            Ok((val, parts))
+        })
+        .collect()
+}
+
+fn valid(val: i64, target: i64, mut parts: VecDeque<i64>, is_part_two: bool) -> bool {
+    if let Some(part) = parts.pop_front() {
+        if val == -1 {
+            valid(part, target, parts.clone(), is_part_two)
+        } else {
+            // a result that overflows can't be the target
+            let valid = |val: Option<i64>| {
+                val.is_some_and(|val| valid(val, target, parts.clone(), is_part_two))
+            };
+            valid(val.checked_add(part))
+                || valid(val.checked_mul(part))
+                || (is_part_two && valid(concat(val, part)))
+        }
+    } else {
+        val == target
+    }
+}
+
+/// `None` on overflow. `0` counts as one digit.
+fn concat(a: i64, b: i64) -> Option<i64> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    a.checked_mul(10i64.checked_pow(digits)?)?.checked_add(b)
+}
+
+fn calibration((target, parts): &(i64, VecDeque<i64>), is_part_two: bool) -> i64 {
+    if valid(-1, *target, parts.clone(), is_part_two) {
+        *target
+    } else {
+        0
+    }
+}
+
+fn part1(equations: &[(i64, VecDeque<i64>)]) -> i64 {
+    equations.iter().map(|eq| calibration(eq, false)).sum()
+}
+
+fn part2(equations: &[(i64, VecDeque<i64>)]) -> i64 {
+    equations.iter().map(|eq| calibration(eq, true)).sum()
+}
+
+fn parallel(equations: &[(i64, VecDeque<i64>)], is_part_two: bool) -> i64 {
+    equations
+        .par_iter()
+        .map(|eq| calibration(eq, is_part_two))
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Vec<(i64, VecDeque<i64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, "parallel", |input| Ok(parallel(input, false).into())),
+            Variant::new(2, "parallel", |input| Ok(parallel(input, true).into())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST).unwrap()), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST).unwrap()), 11387);
+        assert_eq!(parallel(&parse(TEST).unwrap(), true), 11387);
+
+        let edge_cases = parse("10: 1 0\n5: 5 0 0\n7: 9223372036854775807 2 7").unwrap();
+        assert_eq!(part2(&edge_cases), 15);
+    }
+}
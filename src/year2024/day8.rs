@@ -1,11 +1,17 @@
-use crate::utils::{enumerate, read_grid, Point};
-use aoc_runner_derive::aoc;
+use crate::{
+    error::Result,
+    solution::{Solution, Solved},
+    utils::{enumerate, read_grid, Grid, Point},
+};
 use std::collections::HashMap;
 
-fn count(input: &str, is_part_two: bool) -> i32 {
-    let grid = read_grid(input, |_, c| c);
+fn parse(input: &str) -> Result<Grid<char>> {
+    Ok(read_grid(input, |_, c| c))
+}
+
+fn count(grid: &Grid<char>, is_part_two: bool) -> i32 {
     let antennas: HashMap<char, Vec<Point>> =
-        enumerate(&grid).fold(HashMap::new(), |mut map, (pos, &val)| {
+        enumerate(grid).fold(HashMap::new(), |mut map, (pos, &val)| {
             if val != '.' {
                 map.entry(val)
                     .and_modify(|v| v.push(pos))
@@ -16,8 +22,8 @@ fn count(input: &str, is_part_two: bool) -> i32 {
 
     let mut count = 0;
 
-    'outer: for (pos, _) in enumerate(&grid) {
-        for (_, props) in &antennas {
+    'outer: for (pos, _) in enumerate(grid) {
+        for props in antennas.values() {
             for &antenna1 in props {
                 for &antenna2 in props {
                     if antenna1 == antenna2 {
@@ -52,14 +58,33 @@ fn count(input: &str, is_part_two: bool) -> i32 {
     count
 }
 
-#[aoc(day8, part1)]
-fn part1(input: &str) -> i32 {
-    count(input, false)
+fn part1(grid: &Grid<char>) -> i32 {
+    count(grid, false)
+}
+
+fn part2(grid: &Grid<char>) -> i32 {
+    count(grid, true)
 }
 
-#[aoc(day8, part2)]
-fn part2(input: &str) -> i32 {
-    count(input, true)
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
 }
 
 #[cfg(test)]
@@ -81,11 +106,11 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(TEST), 14);
+        assert_eq!(part1(&parse(TEST).unwrap()), 14);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(TEST), 34);
+        assert_eq!(part2(&parse(TEST).unwrap()), 34);
     }
 }
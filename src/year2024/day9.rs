@@ -0,0 +1,200 @@
+use fastrand::Rng;
+
+use crate::{
+    error::{ParseError, Result},
+    solution::{Solution, Solved},
+    validate,
+};
+
+const DAY: u32 = 9;
+
+fn parse(input: &str) -> Result<Vec<(i64, usize)>> {
+    let mut res = vec![];
+
+    for (i, c) in input.strip_suffix('\n').unwrap_or(input).char_indices() {
+        let val = c
+            .to_digit(10)
+            .ok_or_else(|| ParseError::at(DAY, input, i, "a digit"))?;
+        let len = val as usize;
+        if i % 2 == 0 {
+            res.extend(vec![((i / 2) as _, len); len]);
+        } else {
+            res.extend(vec![(-1, len); len]);
+        }
+    }
+
+    Ok(res)
+}
+
+fn part1(map: &[(i64, usize)]) -> i64 {
+    let mut map = map.to_vec();
+
+    let Some(mut i) = map.len().checked_sub(1) else {
+        return 0;
+    };
+    // not necessary, but improves performance by 127x (!!)
+    let mut search_start = 0;
+    loop {
+        if map[i].0 == -1 {
+            // free space up to the start of the disk, nothing left to move
+            let Some(next) = i.checked_sub(map[i].1) else {
+                break;
+            };
+            i = next;
+            continue;
+        }
+
+        for j in search_start..i {
+            if map[j].0 == -1 {
+                map.swap(i, j);
+                search_start = j + 1;
+                break;
+            }
+        }
+
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+    }
+
+    map.iter()
+        .enumerate()
+        .map(|(i, &(val, _))| (i as i64) * val.max(0))
+        .sum()
+}
+
+fn part2(map: &[(i64, usize)]) -> i64 {
+    let mut map = map.to_vec();
+
+    let Some(mut i) = map.len().checked_sub(1) else {
+        return 0;
+    };
+    // not necessary, but improves performance by 6x
+    let mut search_start = 0;
+    loop {
+        if map[i].0 == -1 {
+            // free space up to the start of the disk, nothing left to move
+            let Some(next) = i.checked_sub(map[i].1) else {
+                break;
+            };
+            i = next;
+            continue;
+        }
+
+        let block_size = map[i].1;
+
+        let mut updated_start = false;
+
+        // search_start only takes effect for the next block
+        #[allow(clippy::mut_range_bound)]
+        for j in search_start..i {
+            if map[j].0 == -1 {
+                if !updated_start {
+                    search_start = j;
+                    updated_start = true;
+                }
+
+                let available = map[j].1;
+
+                if available >= block_size {
+                    for k in j..j + block_size {
+                        map.swap(i - k + j, k);
+                    }
+                    for cell in &mut map[j + block_size..j + available] {
+                        cell.1 -= block_size;
+                    }
+
+                    i -= block_size - 1;
+                    break;
+                }
+            }
+        }
+
+        if i == 0 {
+            break;
+        }
+        i -= 1;
+    }
+
+    map.iter()
+        .enumerate()
+        .map(|(i, &(val, _))| (i as i64) * val.max(0))
+        .sum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = super::YEAR;
+    const DAY: u32 = DAY;
+
+    type Input = Vec<(i64, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    /// A single line of at least one digit
+    fn validate(input: &str) -> Vec<ParseError> {
+        let mut lines = input.lines();
+        let first = lines.next().unwrap_or_default();
+
+        let mut errors = validate::only(DAY, first, 1, |c| c.is_ascii_digit(), "a digit");
+        if first.is_empty() {
+            errors.push(ParseError::new(DAY, 1, 1, "a digit").found(""));
+        }
+        if let Some(line) = lines.next() {
+            errors.push(ParseError::new(DAY, 2, 1, "a single line").found(line));
+        }
+        errors
+    }
+
+    fn part1(input: &Self::Input) -> Solved {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Solved {
+        Ok(part2(input).into())
+    }
+
+    /// A disk map of `size` files of 1 to 9 blocks, with 0 to 9 free blocks
+    /// between them
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut input = String::new();
+        for i in 0..size.max(1) {
+            if i > 0 {
+                input.push(char::from(b'0' + rng.u8(0..=9)));
+            }
+            input.push(char::from(b'0' + rng.u8(1..=9)));
+        }
+
+        Some(input + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &str = "2333133121414131402";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(TEST).unwrap()), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(TEST).unwrap()), 2858);
+    }
+
+    #[test]
+    fn empty_files() {
+        for input in ["0", "02"] {
+            assert!(Day9::validate(input).is_empty());
+            assert_eq!(part1(&parse(input).unwrap()), 0);
+            assert_eq!(part2(&parse(input).unwrap()), 0);
+        }
+    }
+}